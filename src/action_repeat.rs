@@ -0,0 +1,193 @@
+//! Agent repeating every sampled action for a number of steps (frame-skip).
+
+use std::marker::PhantomData;
+
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::distributions::{Distribution, Uniform};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::SeededRng;
+use crate::RandomAgentError;
+
+/// Number of steps a sampled action is repeated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RepeatCount {
+    /// Every action is repeated exactly this many steps.
+    Fixed(usize),
+    /// Every action is repeated for a number of steps drawn uniformly from `minimum..=maximum`.
+    Uniform { minimum: usize, maximum: usize },
+}
+
+/// Agent which samples a new action like `RandomAgent` only every few steps and repeats it in
+/// between.
+///
+/// The repetition starts anew on `Agent::reset`.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{ActionRepeatAgent, RepeatCount};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: ActionRepeatAgent<f64> = ActionRepeatAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     RepeatCount::Uniform { minimum: 1, maximum: 8 },
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let chosen_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_eq!(&vec![1], chosen_action.dimensions());
+/// ```
+///
+/// Storing and loading resumes the exact trajectory:
+///
+/// ```
+/// use gymnarium_agents_random::{ActionRepeatAgent, RepeatCount};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: ActionRepeatAgent<f64> = ActionRepeatAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     RepeatCount::Fixed(4),
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+/// for _ in 0..6 {
+///     agent.choose_action(&EnvironmentState::default()).unwrap();
+/// }
+/// assert_eq!(2, agent.remaining_repeats());
+///
+/// let mut resumed_agent: ActionRepeatAgent<f64> = ActionRepeatAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     RepeatCount::Fixed(4),
+/// ).unwrap();
+/// resumed_agent.load(agent.store()).unwrap();
+///
+/// for _ in 0..20 {
+///     assert_eq!(
+///         agent.choose_action(&EnvironmentState::default()).unwrap(),
+///         resumed_agent.choose_action(&EnvironmentState::default()).unwrap(),
+///     );
+/// }
+/// ```
+pub struct ActionRepeatAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    repeat_count: RepeatCount,
+    current_action: Option<AgentAction>,
+    remaining_repeats: usize,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> ActionRepeatAgent<R> {
+    /// Creates a new ActionRepeatAgent with the provided ActionSpace and number of repeats.
+    pub fn with(
+        action_spaces: ActionSpace,
+        repeat_count: RepeatCount,
+    ) -> Result<Self, RandomAgentError> {
+        match repeat_count {
+            RepeatCount::Fixed(0) => {
+                return Err(RandomAgentError::InvalidConfiguration {
+                    reason: "fixed repeat count has to be at least 1".to_string(),
+                })
+            }
+            RepeatCount::Uniform { minimum, maximum } if minimum == 0 || minimum > maximum => {
+                return Err(RandomAgentError::InvalidConfiguration {
+                    reason: format!(
+                        "repeat count range {}..={} has to be non-empty and start at 1 or above",
+                        minimum, maximum
+                    ),
+                })
+            }
+            _ => {}
+        }
+        Ok(Self {
+            action_spaces,
+            repeat_count,
+            current_action: None,
+            remaining_repeats: 0,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns how many more steps the current action is going to be repeated.
+    pub fn remaining_repeats(&self) -> usize {
+        self.remaining_repeats
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, ActionRepeatAgentStorage> for ActionRepeatAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        self.current_action = None;
+        self.remaining_repeats = 0;
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let action = match self.current_action.take() {
+            Some(action) if self.remaining_repeats > 0 => action,
+            _ => {
+                self.remaining_repeats = match self.repeat_count {
+                    RepeatCount::Fixed(count) => count,
+                    RepeatCount::Uniform { minimum, maximum } => {
+                        Uniform::new_inclusive(minimum, maximum).sample(&mut self.rng.rng)
+                    }
+                };
+                self.action_spaces.sample_with(&mut self.rng.rng)
+            }
+        };
+        self.remaining_repeats -= 1;
+        self.current_action = Some(action.clone());
+        Ok(action)
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: ActionRepeatAgentStorage) -> Result<(), RandomAgentError> {
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.current_action = data.current_action;
+        self.remaining_repeats = data.remaining_repeats;
+        Ok(())
+    }
+
+    fn store(&self) -> ActionRepeatAgentStorage {
+        ActionRepeatAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            current_action: self.current_action.clone(),
+            remaining_repeats: self.remaining_repeats,
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ActionRepeatAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    current_action: Option<AgentAction>,
+    remaining_repeats: usize,
+}
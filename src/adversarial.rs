@@ -0,0 +1,320 @@
+//! Agent deliberately producing invalid actions for testing the error paths of environments.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Kind of action an AdversarialAgent produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InvalidActionKind {
+    /// A valid action within the ActionSpace.
+    Valid,
+    /// One value lies outside of the boundaries of its dimension.
+    OutOfBounds,
+    /// One float value is NaN.
+    NotANumber,
+    /// One float value is positive or negative infinity.
+    Infinite,
+    /// One value has the wrong type, e.g. an integer within a float dimension.
+    WrongValueType,
+    /// The action has one value too many or too few.
+    WrongShape,
+}
+
+/// Probabilities of the kinds of invalid actions an AdversarialAgent produces.
+///
+/// The remaining probability up to one is used for valid actions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvalidActionProbabilities {
+    pub out_of_bounds: f64,
+    pub not_a_number: f64,
+    pub infinite: f64,
+    pub wrong_value_type: f64,
+    pub wrong_shape: f64,
+}
+
+impl Default for InvalidActionProbabilities {
+    fn default() -> Self {
+        Self {
+            out_of_bounds: 0.2,
+            not_a_number: 0.2,
+            infinite: 0.2,
+            wrong_value_type: 0.2,
+            wrong_shape: 0.2,
+        }
+    }
+}
+
+impl InvalidActionProbabilities {
+    fn kinds(&self) -> [(InvalidActionKind, f64); 5] {
+        [
+            (InvalidActionKind::OutOfBounds, self.out_of_bounds),
+            (InvalidActionKind::NotANumber, self.not_a_number),
+            (InvalidActionKind::Infinite, self.infinite),
+            (InvalidActionKind::WrongValueType, self.wrong_value_type),
+            (InvalidActionKind::WrongShape, self.wrong_shape),
+        ]
+    }
+}
+
+/// Agent which deliberately produces invalid actions with configurable probabilities, so that
+/// test suites can check whether environments reject them properly.
+///
+/// Every invalid action starts as a uniformly sampled valid action of which a single, randomly
+/// chosen value (or the shape) is broken. If the chosen kind cannot be produced for the ActionSpace
+/// (e.g. NaN without any float dimension) a valid action is produced instead. The kind actually
+/// produced in every step is recorded in the history.
+///
+/// Float dimensions with infinite boundaries get valid values normally distributed around their
+/// finite end (or zero) instead.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{AdversarialAgent, InvalidActionKind, InvalidActionProbabilities};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: AdversarialAgent<f64> = AdversarialAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     InvalidActionProbabilities {
+///         out_of_bounds: 0.0,
+///         not_a_number: 1.0,
+///         infinite: 0.0,
+///         wrong_value_type: 0.0,
+///         wrong_shape: 0.0,
+///     },
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_eq!(Some(InvalidActionKind::NotANumber), agent.last_kind());
+/// ```
+pub struct AdversarialAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    probabilities: InvalidActionProbabilities,
+    history: Vec<InvalidActionKind>,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> AdversarialAgent<R> {
+    /// Creates a new AdversarialAgent with the provided ActionSpace and probabilities of invalid
+    /// actions.
+    pub fn with(
+        action_spaces: ActionSpace,
+        probabilities: InvalidActionProbabilities,
+    ) -> Result<Self, RandomAgentError> {
+        let kinds = probabilities.kinds();
+        if kinds
+            .iter()
+            .any(|(_, probability)| !(0.0..=1.0).contains(probability))
+            || kinds
+                .iter()
+                .map(|(_, probability)| probability)
+                .sum::<f64>()
+                > 1.0
+        {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "invalid action probabilities have to be between 0 and 1 and sum up to at most 1, but are {:?}",
+                    probabilities
+                ),
+            });
+        }
+        Ok(Self {
+            boundaries: util::flatten_boundaries(&action_spaces),
+            action_spaces,
+            probabilities,
+            history: Vec::new(),
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns the kind of every action produced so far, in order.
+    pub fn history(&self) -> &[InvalidActionKind] {
+        &self.history
+    }
+
+    /// Returns the kind of the last produced action.
+    pub fn last_kind(&self) -> Option<InvalidActionKind> {
+        self.history.last().copied()
+    }
+
+    /// Forgets the kinds of all produced actions.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    fn draw_kind(&mut self) -> InvalidActionKind {
+        let mut draw: f64 = self.rng.rng.gen();
+        for (kind, probability) in self.probabilities.kinds().iter() {
+            if draw < *probability {
+                return *kind;
+            }
+            draw -= probability;
+        }
+        InvalidActionKind::Valid
+    }
+}
+
+/// Breaks one value of `values` according to `kind` and returns whether that was possible.
+fn break_value<G: Rng>(
+    kind: InvalidActionKind,
+    boundaries: &[DimensionBoundaries],
+    values: &mut [DimensionValue],
+    rng: &mut G,
+) -> bool {
+    let candidates: Vec<usize> = (0..values.len())
+        .filter(|index| match (kind, &boundaries[*index]) {
+            (InvalidActionKind::OutOfBounds, DimensionBoundaries::Integer { minimum, maximum }) => {
+                *minimum > i64::MIN || *maximum < i64::MAX
+            }
+            (InvalidActionKind::OutOfBounds, DimensionBoundaries::Float { minimum, maximum }) => {
+                minimum.is_finite() || maximum.is_finite()
+            }
+            (InvalidActionKind::NotANumber, boundaries)
+            | (InvalidActionKind::Infinite, boundaries) => {
+                matches!(boundaries, DimensionBoundaries::Float { .. })
+            }
+            (InvalidActionKind::WrongValueType, _) => true,
+            _ => false,
+        })
+        .collect();
+    let index = match candidates.choose(rng) {
+        Some(index) => *index,
+        None => return false,
+    };
+    values[index] = match (kind, &boundaries[index]) {
+        (InvalidActionKind::OutOfBounds, DimensionBoundaries::Integer { minimum, maximum }) => {
+            let below = *minimum > i64::MIN && (*maximum == i64::MAX || rng.gen_bool(0.5));
+            DimensionValue::Integer(if below { *minimum - 1 } else { *maximum + 1 })
+        }
+        (InvalidActionKind::OutOfBounds, DimensionBoundaries::Float { minimum, maximum }) => {
+            let distance = if minimum.is_finite() && maximum.is_finite() {
+                (*maximum - *minimum).max(1.0)
+            } else {
+                1.0
+            };
+            let below = minimum.is_finite() && (!maximum.is_finite() || rng.gen_bool(0.5));
+            DimensionValue::Float(if below {
+                *minimum - distance
+            } else {
+                *maximum + distance
+            })
+        }
+        (InvalidActionKind::NotANumber, _) => DimensionValue::Float(f64::NAN),
+        (InvalidActionKind::Infinite, _) => DimensionValue::Float(if rng.gen_bool(0.5) {
+            f64::INFINITY
+        } else {
+            f64::NEG_INFINITY
+        }),
+        (InvalidActionKind::WrongValueType, _) => match values[index] {
+            DimensionValue::Integer(value) => DimensionValue::Float(value as f64),
+            DimensionValue::Float(value) => DimensionValue::Integer(value.round() as i64),
+        },
+        _ => return false,
+    };
+    true
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, AdversarialAgentStorage> for AdversarialAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let kind = self.draw_kind();
+        let mut values: Vec<DimensionValue> = Vec::with_capacity(self.boundaries.len());
+        for boundaries in &self.boundaries {
+            values.push(util::sample_valid(boundaries, &mut self.rng.rng));
+        }
+        let (kind, action) = match kind {
+            InvalidActionKind::Valid => (
+                kind,
+                util::compose_action(self.action_spaces.dimensions(), values),
+            ),
+            InvalidActionKind::WrongShape => {
+                if values.is_empty() || self.rng.rng.gen_bool(0.5) {
+                    let index = self.rng.rng.gen_range(0, self.boundaries.len().max(1));
+                    let extra = match self.boundaries.get(index) {
+                        Some(boundaries) => util::sample_valid(boundaries, &mut self.rng.rng),
+                        None => DimensionValue::Integer(0),
+                    };
+                    values.push(extra);
+                } else {
+                    values.pop();
+                }
+                let length = values.len();
+                (kind, util::compose_action(&[length], values))
+            }
+            _ => {
+                let kind = if break_value(kind, &self.boundaries, &mut values, &mut self.rng.rng) {
+                    kind
+                } else {
+                    InvalidActionKind::Valid
+                };
+                (
+                    kind,
+                    util::compose_action(self.action_spaces.dimensions(), values),
+                )
+            }
+        };
+        self.history.push(kind);
+        Ok(action)
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: AdversarialAgentStorage) -> Result<(), RandomAgentError> {
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.history = data.history;
+        Ok(())
+    }
+
+    fn store(&self) -> AdversarialAgentStorage {
+        AdversarialAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            history: self.history.clone(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AdversarialAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    history: Vec<InvalidActionKind>,
+}
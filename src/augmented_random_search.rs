@@ -0,0 +1,416 @@
+//! Augmented Random Search (Mania et al., 2018) over linear policies.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use serde::{Deserialize, Serialize};
+
+use crate::policy::{self, LinearPolicyParameters, OutputMapping};
+use crate::util::SeededRng;
+use crate::RandomAgentError;
+
+/// Standard deviations below this value are replaced by one when normalizing states.
+const MINIMUM_STANDARD_DEVIATION: f64 = 1e-8;
+
+/// Hyperparameters of an AugmentedRandomSearchAgent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AugmentedRandomSearchParameters {
+    /// Step size `alpha` of every update.
+    pub step_size: f64,
+    /// Standard deviation `nu` of the parameter perturbations.
+    pub exploration_noise: f64,
+    /// Number of perturbation directions `N` per update.
+    pub directions: usize,
+    /// Number of best directions `b` used for the update.
+    pub top_directions: usize,
+    /// Whether states are normalized by their running mean and standard deviation (ARS V2).
+    pub normalize_states: bool,
+}
+
+impl Default for AugmentedRandomSearchParameters {
+    fn default() -> Self {
+        Self {
+            step_size: 0.02,
+            exploration_noise: 0.03,
+            directions: 8,
+            top_directions: 4,
+            normalize_states: true,
+        }
+    }
+}
+
+/// Running mean and variance of the states, updated with Welford's algorithm.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct StateStatistics {
+    count: u64,
+    mean: Vec<f64>,
+    squared_differences: Vec<f64>,
+}
+
+impl StateStatistics {
+    fn new(input_size: usize) -> Self {
+        Self {
+            count: 0,
+            mean: vec![0.0; input_size],
+            squared_differences: vec![0.0; input_size],
+        }
+    }
+
+    fn push(&mut self, features: &[f64]) {
+        self.count += 1;
+        let count = self.count as f64;
+        for ((mean, squared_differences), feature) in self
+            .mean
+            .iter_mut()
+            .zip(self.squared_differences.iter_mut())
+            .zip(features.iter())
+        {
+            let difference = feature - *mean;
+            *mean += difference / count;
+            *squared_differences += difference * (feature - *mean);
+        }
+    }
+
+    fn standard_deviations(&self) -> Vec<f64> {
+        self.squared_differences
+            .iter()
+            .map(|squared_differences| {
+                let standard_deviation = if self.count < 2 {
+                    0.0
+                } else {
+                    (squared_differences / self.count as f64).sqrt()
+                };
+                if standard_deviation < MINIMUM_STANDARD_DEVIATION {
+                    1.0
+                } else {
+                    standard_deviation
+                }
+            })
+            .collect()
+    }
+}
+
+/// Agent learning a linear policy (see `RandomLinearPolicyAgent`) with Augmented Random Search.
+///
+/// Every iteration draws `directions` perturbations `δ` and evaluates `θ + νδ` and `θ - νδ` for
+/// one episode each, in this order. An episode lasts from one reset to the next (or until
+/// `process_reward` receives `done`) and its return is the sum of the processed rewards. After all
+/// `2 · directions` episodes the `top_directions` best directions are combined into the update
+/// `θ += α / (b · σ_R) · Σ (r₊ - r₋) δ`, where `σ_R` is the standard deviation of their returns.
+///
+/// With state normalization the states are shifted and scaled by the mean and standard deviation
+/// of all states seen so far, which are frozen at the start of every iteration.
+///
+/// Reseeding restarts the current iteration with new perturbations drawn from the new seed.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{AugmentedRandomSearchAgent, AugmentedRandomSearchParameters};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut agent: AugmentedRandomSearchAgent<f64> = AugmentedRandomSearchAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     1,
+///     AugmentedRandomSearchParameters::default(),
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+///
+/// let state = EnvironmentState::simple(vec![DimensionValue::Float(1.0)]);
+/// for _ in 0..16 {
+///     agent.reset().unwrap();
+///     let action = agent.choose_action(&state).unwrap();
+///     let reward = match action[&[0]] {
+///         DimensionValue::Float(value) => value,
+///         _ => panic!("expected a float"),
+///     };
+///     agent.process_reward(&state, &action, &state, reward, true).unwrap();
+/// }
+///
+/// assert_eq!(1, agent.iterations());
+/// ```
+pub struct AugmentedRandomSearchAgent<R: Reward + Into<f64>> {
+    mapping: OutputMapping,
+    input_size: usize,
+    hyperparameters: AugmentedRandomSearchParameters,
+    parameters: LinearPolicyParameters,
+    perturbations: Vec<LinearPolicyParameters>,
+    returns: Vec<f64>,
+    active_parameters: LinearPolicyParameters,
+    episode_return: f64,
+    episode_running: bool,
+    statistics: StateStatistics,
+    normalization_mean: Vec<f64>,
+    normalization_standard_deviations: Vec<f64>,
+    iterations: u64,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward + Into<f64>> AugmentedRandomSearchAgent<R> {
+    /// Creates a new AugmentedRandomSearchAgent with the provided ActionSpace for states
+    /// consisting of `input_size` values, starting with parameters of zero.
+    pub fn with(
+        action_spaces: ActionSpace,
+        input_size: usize,
+        hyperparameters: AugmentedRandomSearchParameters,
+    ) -> Result<Self, RandomAgentError> {
+        if !(hyperparameters.step_size.is_finite() && hyperparameters.step_size > 0.0)
+            || !(hyperparameters.exploration_noise.is_finite()
+                && hyperparameters.exploration_noise > 0.0)
+        {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "step size and exploration noise have to be finite and positive, but are {} and {}",
+                    hyperparameters.step_size, hyperparameters.exploration_noise
+                ),
+            });
+        }
+        if hyperparameters.top_directions == 0
+            || hyperparameters.top_directions > hyperparameters.directions
+        {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "top directions have to be between 1 and the {} directions, but are {}",
+                    hyperparameters.directions, hyperparameters.top_directions
+                ),
+            });
+        }
+        let mapping = OutputMapping::new(&action_spaces);
+        let parameters = LinearPolicyParameters::zeros(input_size, mapping.output_size());
+        let mut agent = Self {
+            mapping,
+            input_size,
+            hyperparameters,
+            active_parameters: parameters.clone(),
+            parameters,
+            perturbations: Vec::new(),
+            returns: Vec::new(),
+            episode_return: 0.0,
+            episode_running: false,
+            statistics: StateStatistics::new(input_size),
+            normalization_mean: vec![0.0; input_size],
+            normalization_standard_deviations: vec![1.0; input_size],
+            iterations: 0,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        };
+        agent.sample_perturbations();
+        agent.activate();
+        Ok(agent)
+    }
+
+    /// Returns the parameters `θ` learned so far.
+    pub fn parameters(&self) -> &LinearPolicyParameters {
+        &self.parameters
+    }
+
+    /// Returns the perturbed parameters used within the current episode.
+    pub fn active_parameters(&self) -> &LinearPolicyParameters {
+        &self.active_parameters
+    }
+
+    /// Returns the number of updates done so far.
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    fn sample_perturbations(&mut self) {
+        let output_size = self.mapping.output_size();
+        let input_size = self.input_size;
+        let rng = &mut self.rng.rng;
+        self.perturbations = (0..self.hyperparameters.directions)
+            .map(|_| LinearPolicyParameters::sample(input_size, output_size, 1.0, rng))
+            .collect();
+    }
+
+    /// Chooses the perturbed parameters of the next episode.
+    fn activate(&mut self) {
+        let episode = self.returns.len();
+        let sign = if episode % 2 == 0 { 1.0 } else { -1.0 };
+        self.active_parameters = self.parameters.added(
+            &self.perturbations[episode / 2],
+            sign * self.hyperparameters.exploration_noise,
+        );
+    }
+
+    fn finish_episode(&mut self) {
+        if !self.episode_running {
+            return;
+        }
+        self.episode_running = false;
+        self.returns.push(self.episode_return);
+        if self.returns.len() == 2 * self.hyperparameters.directions {
+            self.update();
+        }
+    }
+
+    fn update(&mut self) {
+        let mut directions: Vec<usize> = (0..self.hyperparameters.directions).collect();
+        directions.sort_by(|first, second| {
+            let first = self.returns[2 * first].max(self.returns[2 * first + 1]);
+            let second = self.returns[2 * second].max(self.returns[2 * second + 1]);
+            second
+                .partial_cmp(&first)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        directions.truncate(self.hyperparameters.top_directions);
+
+        let used_returns: Vec<f64> = directions
+            .iter()
+            .flat_map(|direction| {
+                vec![self.returns[2 * direction], self.returns[2 * direction + 1]]
+            })
+            .collect();
+        let mean = used_returns.iter().sum::<f64>() / used_returns.len() as f64;
+        let standard_deviation = (used_returns
+            .iter()
+            .map(|episode_return| (episode_return - mean).powi(2))
+            .sum::<f64>()
+            / used_returns.len() as f64)
+            .sqrt();
+        let standard_deviation = if standard_deviation < MINIMUM_STANDARD_DEVIATION {
+            1.0
+        } else {
+            standard_deviation
+        };
+
+        let factor = self.hyperparameters.step_size
+            / (self.hyperparameters.top_directions as f64 * standard_deviation);
+        for direction in directions {
+            let difference = self.returns[2 * direction] - self.returns[2 * direction + 1];
+            self.parameters = self
+                .parameters
+                .added(&self.perturbations[direction], factor * difference);
+        }
+
+        if self.hyperparameters.normalize_states {
+            self.normalization_mean = self.statistics.mean.clone();
+            self.normalization_standard_deviations = self.statistics.standard_deviations();
+        }
+        self.returns.clear();
+        self.iterations += 1;
+        self.sample_perturbations();
+    }
+}
+
+impl<R: Reward + Into<f64>> Agent<RandomAgentError, R, AugmentedRandomSearchAgentStorage>
+    for AugmentedRandomSearchAgent<R>
+{
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        self.returns.clear();
+        self.episode_running = false;
+        self.sample_perturbations();
+        self.activate();
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        self.finish_episode();
+        self.activate();
+        self.episode_return = 0.0;
+        Ok(())
+    }
+
+    fn choose_action(&mut self, state: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let mut features = policy::state_features(state, self.input_size)?;
+        if self.hyperparameters.normalize_states {
+            self.statistics.push(&features);
+            for ((feature, mean), standard_deviation) in features
+                .iter_mut()
+                .zip(self.normalization_mean.iter())
+                .zip(self.normalization_standard_deviations.iter())
+            {
+                *feature = (*feature - mean) / standard_deviation;
+            }
+        }
+        Ok(self
+            .mapping
+            .action(&self.active_parameters.evaluate(&features)))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        reward: R,
+        done: bool,
+    ) -> Result<(), RandomAgentError> {
+        self.episode_return += reward.into();
+        self.episode_running = true;
+        if done {
+            self.finish_episode();
+        }
+        Ok(())
+    }
+
+    fn load(&mut self, data: AugmentedRandomSearchAgentStorage) -> Result<(), RandomAgentError> {
+        let output_size = self.mapping.output_size();
+        data.parameters.check_shape(self.input_size, output_size)?;
+        if data.perturbations.len() != self.hyperparameters.directions
+            || data.returns.len() >= 2 * self.hyperparameters.directions
+            || data.statistics.mean.len() != self.input_size
+            || data.statistics.squared_differences.len() != self.input_size
+            || data.normalization_mean.len() != self.input_size
+            || data.normalization_standard_deviations.len() != self.input_size
+        {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: "stored search state doesn't match the hyperparameters".to_string(),
+            });
+        }
+        for perturbation in &data.perturbations {
+            perturbation.check_shape(self.input_size, output_size)?;
+        }
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.parameters = data.parameters;
+        self.perturbations = data.perturbations;
+        self.returns = data.returns;
+        self.episode_return = data.episode_return;
+        self.episode_running = data.episode_running;
+        self.statistics = data.statistics;
+        self.normalization_mean = data.normalization_mean;
+        self.normalization_standard_deviations = data.normalization_standard_deviations;
+        self.iterations = data.iterations;
+        self.activate();
+        Ok(())
+    }
+
+    fn store(&self) -> AugmentedRandomSearchAgentStorage {
+        AugmentedRandomSearchAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            parameters: self.parameters.clone(),
+            perturbations: self.perturbations.clone(),
+            returns: self.returns.clone(),
+            episode_return: self.episode_return,
+            episode_running: self.episode_running,
+            statistics: self.statistics.clone(),
+            normalization_mean: self.normalization_mean.clone(),
+            normalization_standard_deviations: self.normalization_standard_deviations.clone(),
+            iterations: self.iterations,
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AugmentedRandomSearchAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    parameters: LinearPolicyParameters,
+    perturbations: Vec<LinearPolicyParameters>,
+    returns: Vec<f64>,
+    episode_return: f64,
+    episode_running: bool,
+    statistics: StateStatistics,
+    normalization_mean: Vec<f64>,
+    normalization_standard_deviations: Vec<f64>,
+    iterations: u64,
+}
@@ -0,0 +1,313 @@
+//! Agent producing temporally correlated actions through colored (1/f^beta) noise.
+
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::Rng;
+
+use rand_distr::{Distribution, StandardNormal};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Agent which chooses his float actions by walking through pre-generated colored noise.
+///
+/// For every float dimension a sequence of `sequence_length` values with a power spectral
+/// density proportional to `1/f^beta` is generated on `Agent::reset` (`beta = 0` is white noise,
+/// `beta = 1` pink noise and `beta = 2` red/brownian noise). The sequences are normalized to a
+/// standard deviation of one, scaled to half the width of the boundaries around their midpoint and
+/// finally clipped into the boundaries. If an episode runs longer than the sequences, new ones are
+/// generated. Integer dimensions are sampled uniformly.
+///
+/// The sequences are drawn from the seeded random number generator only, so reseeding with the same
+/// seed reproduces exactly the same sequences.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::ColoredNoiseAgent;
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: ColoredNoiseAgent<f64> = ColoredNoiseAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     1.0,
+///     100,
+/// ).unwrap();
+///
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+/// let first_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+/// let second_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_eq!(first_action, second_action);
+/// ```
+pub struct ColoredNoiseAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    beta: f64,
+    sequence_length: usize,
+    sequences: Vec<Vec<f64>>,
+    step: usize,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> ColoredNoiseAgent<R> {
+    /// Creates a new ColoredNoiseAgent with the provided ActionSpace, exponent `beta` and length of
+    /// the pre-generated sequences.
+    ///
+    /// Fails if any float boundaries are infinite.
+    pub fn with(
+        action_spaces: ActionSpace,
+        beta: f64,
+        sequence_length: usize,
+    ) -> Result<Self, RandomAgentError> {
+        if !beta.is_finite() {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!("beta has to be finite, but is {}", beta),
+            });
+        }
+        if sequence_length == 0 {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: "sequence length has to be at least 1".to_string(),
+            });
+        }
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        util::require_finite_boundaries(&boundaries, "colored noise")?;
+        Ok(Self {
+            boundaries,
+            action_spaces,
+            beta,
+            sequence_length,
+            sequences: Vec::new(),
+            step: 0,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns the exponent of the power spectral density.
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Returns the length of the pre-generated sequences.
+    pub fn sequence_length(&self) -> usize {
+        self.sequence_length
+    }
+
+    fn generate_sequences(&mut self) {
+        let mut sequences = Vec::with_capacity(self.boundaries.len());
+        for boundaries in &self.boundaries {
+            if let DimensionBoundaries::Float { .. } = boundaries {
+                sequences.push(colored_noise(
+                    self.beta,
+                    self.sequence_length,
+                    &mut self.rng.rng,
+                ));
+            } else {
+                sequences.push(Vec::new());
+            }
+        }
+        self.sequences = sequences;
+        self.step = 0;
+    }
+}
+
+/// Generates `length` values of gaussian noise with a power spectral density proportional to
+/// `1/f^beta`, normalized to zero mean and a standard deviation of one.
+///
+/// The spectrum is drawn randomly for the next power of two not below `length` and transformed
+/// through an inverse fast fourier transform, of which the first `length` values are kept. This
+/// takes `O(length log length)` time.
+fn colored_noise<G: Rng>(beta: f64, length: usize, rng: &mut G) -> Vec<f64> {
+    let padded_length = length.next_power_of_two();
+    let frequencies = padded_length / 2;
+    let mut spectrum = vec![(0.0, 0.0); padded_length];
+    for (k, coefficient) in spectrum
+        .iter_mut()
+        .enumerate()
+        .take(frequencies + 1)
+        .skip(1)
+    {
+        let amplitude = (k as f64 / padded_length as f64).powf(-beta / 2.0);
+        let real: f64 = StandardNormal.sample(rng);
+        let imaginary: f64 = if k == frequencies {
+            0.0
+        } else {
+            StandardNormal.sample(rng)
+        };
+        *coefficient = (real * amplitude, imaginary * amplitude);
+    }
+    inverse_fft(&mut spectrum);
+    let mut sequence: Vec<f64> = spectrum
+        .into_iter()
+        .take(length)
+        .map(|(real, _)| real)
+        .collect();
+    let mean = sequence.iter().sum::<f64>() / length as f64;
+    let standard_deviation = (sequence
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / length as f64)
+        .sqrt();
+    for value in &mut sequence {
+        *value = if standard_deviation > 0.0 {
+            (*value - mean) / standard_deviation
+        } else {
+            0.0
+        };
+    }
+    sequence
+}
+
+/// Transforms the complex `values` in place into `sum_k values[k] * e^(2 pi i k t / n)` for every
+/// `t` (without normalization) through an iterative radix-2 fast fourier transform.
+///
+/// The number of values has to be a power of two.
+fn inverse_fft(values: &mut [(f64, f64)]) {
+    let length = values.len();
+    let mut reversed = 0;
+    for index in 1..length {
+        let mut bit = length >> 1;
+        while reversed & bit != 0 {
+            reversed ^= bit;
+            bit >>= 1;
+        }
+        reversed |= bit;
+        if index < reversed {
+            values.swap(index, reversed);
+        }
+    }
+    let mut size = 2;
+    while size <= length {
+        let angle = 2.0 * PI / size as f64;
+        for start in (0..length).step_by(size) {
+            for offset in 0..size / 2 {
+                let (sin, cos) = (angle * offset as f64).sin_cos();
+                let (even_real, even_imaginary) = values[start + offset];
+                let (odd_real, odd_imaginary) = values[start + offset + size / 2];
+                let twisted_real = odd_real * cos - odd_imaginary * sin;
+                let twisted_imaginary = odd_real * sin + odd_imaginary * cos;
+                values[start + offset] =
+                    (even_real + twisted_real, even_imaginary + twisted_imaginary);
+                values[start + offset + size / 2] =
+                    (even_real - twisted_real, even_imaginary - twisted_imaginary);
+            }
+        }
+        size <<= 1;
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, ColoredNoiseAgentStorage> for ColoredNoiseAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        self.sequences = Vec::new();
+        self.step = 0;
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        self.generate_sequences();
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        if self.sequences.is_empty() || self.step >= self.sequence_length {
+            self.generate_sequences();
+        }
+        let mut values = Vec::with_capacity(self.boundaries.len());
+        for (boundaries, sequence) in self.boundaries.iter().zip(self.sequences.iter()) {
+            values.push(match boundaries {
+                DimensionBoundaries::Float { minimum, maximum } => {
+                    let midpoint = minimum / 2.0 + maximum / 2.0;
+                    let half_width = maximum / 2.0 - minimum / 2.0;
+                    DimensionValue::Float(
+                        (midpoint + sequence[self.step] * half_width)
+                            .max(*minimum)
+                            .min(*maximum),
+                    )
+                }
+                DimensionBoundaries::Integer { .. } => {
+                    util::sample_uniform(boundaries, &mut self.rng.rng)
+                }
+            });
+        }
+        self.step += 1;
+        Ok(util::compose_action(
+            self.action_spaces.dimensions(),
+            values,
+        ))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: ColoredNoiseAgentStorage) -> Result<(), RandomAgentError> {
+        if !data.sequences.is_empty() && data.sequences.len() != self.boundaries.len() {
+            return Err(RandomAgentError::ParameterCountMismatch {
+                expected: self.boundaries.len(),
+                actual: data.sequences.len(),
+            });
+        }
+        let sequences_fit =
+            data.sequences
+                .iter()
+                .zip(self.boundaries.iter())
+                .all(|(sequence, boundaries)| match boundaries {
+                    DimensionBoundaries::Float { .. } => sequence.len() == self.sequence_length,
+                    DimensionBoundaries::Integer { .. } => sequence.is_empty(),
+                });
+        if !sequences_fit || data.step > self.sequence_length {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "stored sequences and step don't match the sequence length of {}",
+                    self.sequence_length
+                ),
+            });
+        }
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.sequences = data.sequences;
+        self.step = data.step;
+        Ok(())
+    }
+
+    fn store(&self) -> ColoredNoiseAgentStorage {
+        ColoredNoiseAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            sequences: self.sequences.clone(),
+            step: self.step,
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ColoredNoiseAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    sequences: Vec<Vec<f64>>,
+    step: usize,
+}
@@ -0,0 +1,179 @@
+//! Agent sampling actions until they satisfy a user-provided constraint.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::SeededRng;
+use crate::RandomAgentError;
+
+/// Decides whether a sampled action is acceptable.
+///
+/// It is implemented for all closures `Fn(&AgentAction, &EnvironmentState) -> bool`.
+pub trait ActionConstraint {
+    /// Returns whether the action may be chosen within the given state.
+    fn is_satisfied(&self, action: &AgentAction, state: &EnvironmentState) -> bool;
+}
+
+impl<F> ActionConstraint for F
+where
+    F: Fn(&AgentAction, &EnvironmentState) -> bool,
+{
+    fn is_satisfied(&self, action: &AgentAction, state: &EnvironmentState) -> bool {
+        self(action, state)
+    }
+}
+
+/// Counts of sampled and accepted actions of a ConstrainedRandomAgent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AcceptanceStatistics {
+    /// Number of actions sampled in total.
+    pub attempts: u64,
+    /// Number of sampled actions which satisfied the constraint.
+    pub accepted: u64,
+}
+
+impl AcceptanceStatistics {
+    /// Returns the fraction of sampled actions which got accepted or `None` if nothing has been
+    /// sampled yet.
+    pub fn acceptance_rate(&self) -> Option<f64> {
+        if self.attempts == 0 {
+            None
+        } else {
+            Some(self.accepted as f64 / self.attempts as f64)
+        }
+    }
+}
+
+/// Agent which samples actions like `RandomAgent` until one satisfies the ActionConstraint.
+///
+/// If none of `maximum_attempts` samples satisfies the constraint `choose_action` fails with
+/// `RandomAgentError::MaximumAttemptsExceeded`.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::ConstrainedRandomAgent;
+/// use gymnarium_base::{ActionSpace, AgentAction, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut agent: ConstrainedRandomAgent<f64, _> = ConstrainedRandomAgent::with(
+///     ActionSpace::simple(vec![
+///         DimensionBoundaries::from(0.0..=1.0),
+///         DimensionBoundaries::from(0.0..=1.0),
+///     ]),
+///     |action: &AgentAction, _: &EnvironmentState| match (&action[&[0]], &action[&[1]]) {
+///         (DimensionValue::Float(first), DimensionValue::Float(second)) => first + second <= 1.0,
+///         _ => false,
+///     },
+///     1_000,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_eq!(1, agent.statistics().accepted);
+/// ```
+pub struct ConstrainedRandomAgent<R: Reward, C: ActionConstraint> {
+    action_spaces: ActionSpace,
+    constraint: C,
+    maximum_attempts: u64,
+    statistics: AcceptanceStatistics,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward, C: ActionConstraint> ConstrainedRandomAgent<R, C> {
+    /// Creates a new ConstrainedRandomAgent with the provided ActionSpace and ActionConstraint,
+    /// sampling at most `maximum_attempts` actions per chosen action.
+    pub fn with(
+        action_spaces: ActionSpace,
+        constraint: C,
+        maximum_attempts: u64,
+    ) -> Result<Self, RandomAgentError> {
+        if maximum_attempts == 0 {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: "maximum attempts have to be at least 1".to_string(),
+            });
+        }
+        Ok(Self {
+            action_spaces,
+            constraint,
+            maximum_attempts,
+            statistics: AcceptanceStatistics::default(),
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns how many actions have been sampled and accepted so far.
+    pub fn statistics(&self) -> &AcceptanceStatistics {
+        &self.statistics
+    }
+}
+
+impl<R: Reward, C: ActionConstraint> Agent<RandomAgentError, R, ConstrainedRandomAgentStorage>
+    for ConstrainedRandomAgent<R, C>
+{
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn choose_action(&mut self, state: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        for _ in 0..self.maximum_attempts {
+            let action = self.action_spaces.sample_with(&mut self.rng.rng);
+            self.statistics.attempts += 1;
+            if self.constraint.is_satisfied(&action, state) {
+                self.statistics.accepted += 1;
+                return Ok(action);
+            }
+        }
+        Err(RandomAgentError::MaximumAttemptsExceeded {
+            attempts: self.maximum_attempts,
+        })
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: ConstrainedRandomAgentStorage) -> Result<(), RandomAgentError> {
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.statistics = data.statistics;
+        Ok(())
+    }
+
+    fn store(&self) -> ConstrainedRandomAgentStorage {
+        ConstrainedRandomAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            statistics: self.statistics.clone(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ConstrainedRandomAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    statistics: AcceptanceStatistics,
+}
@@ -0,0 +1,448 @@
+//! Cross-entropy method over open-loop action sequences.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::Rng;
+
+use rand_distr::StandardNormal;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Integer dimensions with at most this many values get a categorical distribution, larger ones
+/// a Gaussian distribution whose samples are rounded.
+pub const MAXIMUM_CATEGORICAL_VALUES: i64 = 256;
+
+/// Hyperparameters of a CrossEntropyAgent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrossEntropyParameters {
+    /// Number of episodes evaluated per refit.
+    pub population_size: usize,
+    /// Fraction of the best episodes the distribution is refitted to.
+    pub elite_fraction: f64,
+    /// Weight of the previous distribution parameters when refitting, between 0 (inclusive) and
+    /// 1 (exclusive).
+    pub smoothing: f64,
+    /// Number of steps with their own distribution. Later steps use the last one.
+    pub horizon: usize,
+}
+
+impl Default for CrossEntropyParameters {
+    fn default() -> Self {
+        Self {
+            population_size: 20,
+            elite_fraction: 0.2,
+            smoothing: 0.5,
+            horizon: 1,
+        }
+    }
+}
+
+/// Distribution of a single dimension at a single step of a CrossEntropyAgent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CrossEntropyDistribution {
+    /// Normal distribution whose samples are clamped into the boundaries.
+    Gaussian { mean: f64, standard_deviation: f64 },
+    /// Probability of every integer from the minimum to the maximum of the boundaries.
+    Categorical { probabilities: Vec<f64> },
+}
+
+impl CrossEntropyDistribution {
+    /// Returns the distribution covering the boundaries as broadly as possible.
+    fn initial(boundaries: &DimensionBoundaries) -> Self {
+        match boundaries {
+            DimensionBoundaries::Integer { minimum, maximum } => {
+                match maximum.checked_sub(*minimum) {
+                    Some(difference) if difference < MAXIMUM_CATEGORICAL_VALUES => {
+                        let count = difference as usize + 1;
+                        Self::Categorical {
+                            probabilities: vec![1.0 / count as f64; count],
+                        }
+                    }
+                    _ => Self::Gaussian {
+                        mean: *minimum as f64 / 2.0 + *maximum as f64 / 2.0,
+                        standard_deviation: *maximum as f64 / 2.0 - *minimum as f64 / 2.0,
+                    },
+                }
+            }
+            DimensionBoundaries::Float { minimum, maximum }
+                if minimum.is_finite() && maximum.is_finite() =>
+            {
+                Self::Gaussian {
+                    mean: minimum / 2.0 + maximum / 2.0,
+                    standard_deviation: maximum / 2.0 - minimum / 2.0,
+                }
+            }
+            DimensionBoundaries::Float { minimum, maximum } => Self::Gaussian {
+                mean: 0.0f64.max(*minimum).min(*maximum),
+                standard_deviation: 1.0,
+            },
+        }
+    }
+
+    /// Returns whether the distribution fits the boundaries, like the initial one does.
+    fn fits(&self, boundaries: &DimensionBoundaries) -> bool {
+        match (self, Self::initial(boundaries)) {
+            (Self::Gaussian { .. }, Self::Gaussian { .. }) => true,
+            (
+                Self::Categorical { probabilities },
+                Self::Categorical {
+                    probabilities: initial,
+                },
+            ) => probabilities.len() == initial.len(),
+            _ => false,
+        }
+    }
+
+    fn sample<G: Rng>(&self, boundaries: &DimensionBoundaries, rng: &mut G) -> DimensionValue {
+        match self {
+            Self::Gaussian {
+                mean,
+                standard_deviation,
+            } => util::clamp_into(
+                boundaries,
+                mean + standard_deviation * rng.sample::<f64, _>(StandardNormal),
+            ),
+            Self::Categorical { probabilities } => {
+                let minimum = match boundaries {
+                    DimensionBoundaries::Integer { minimum, .. } => *minimum,
+                    DimensionBoundaries::Float { minimum, .. } => *minimum as i64,
+                };
+                let mut draw: f64 = rng.gen();
+                let mut index = probabilities.len() - 1;
+                for (candidate, probability) in probabilities.iter().enumerate() {
+                    if draw < *probability {
+                        index = candidate;
+                        break;
+                    }
+                    draw -= probability;
+                }
+                DimensionValue::Integer(minimum + index as i64)
+            }
+        }
+    }
+
+    /// Moves the parameters towards the ones fitted to the elite values.
+    fn refit(
+        &mut self,
+        boundaries: &DimensionBoundaries,
+        elite: &[DimensionValue],
+        smoothing: f64,
+    ) {
+        if elite.is_empty() {
+            return;
+        }
+        let count = elite.len() as f64;
+        match self {
+            Self::Gaussian {
+                mean,
+                standard_deviation,
+            } => {
+                let values: Vec<f64> = elite.iter().map(util::value_as_f64).collect();
+                let elite_mean = values.iter().sum::<f64>() / count;
+                let elite_standard_deviation = (values
+                    .iter()
+                    .map(|value| (value - elite_mean).powi(2))
+                    .sum::<f64>()
+                    / count)
+                    .sqrt();
+                *mean = smoothing * *mean + (1.0 - smoothing) * elite_mean;
+                *standard_deviation =
+                    smoothing * *standard_deviation + (1.0 - smoothing) * elite_standard_deviation;
+            }
+            Self::Categorical { probabilities } => {
+                let minimum = match boundaries {
+                    DimensionBoundaries::Integer { minimum, .. } => *minimum,
+                    DimensionBoundaries::Float { minimum, .. } => *minimum as i64,
+                };
+                let mut frequencies = vec![0.0; probabilities.len()];
+                for value in elite {
+                    if let DimensionValue::Integer(value) = value {
+                        if let Some(frequency) = frequencies.get_mut((value - minimum) as usize) {
+                            *frequency += 1.0 / count;
+                        }
+                    }
+                }
+                for (probability, frequency) in probabilities.iter_mut().zip(frequencies) {
+                    *probability = smoothing * *probability + (1.0 - smoothing) * frequency;
+                }
+            }
+        }
+    }
+}
+
+/// Agent optimizing a distribution over open-loop action sequences with the cross-entropy method.
+///
+/// Every step `t < horizon` of an episode has its own distribution per dimension: a Gaussian for
+/// float dimensions and a categorical for integer dimensions (see `MAXIMUM_CATEGORICAL_VALUES`).
+/// Steps beyond the horizon are sampled from the distribution of the last step and aren't used
+/// for refitting. The states are ignored.
+///
+/// An episode lasts from one reset to the next (or until `process_reward` receives `done`) and its
+/// return is the sum of the processed rewards. Once `population_size` episodes have been evaluated,
+/// every distribution is refitted to the values chosen within the best `elite_fraction` of them,
+/// keeping `smoothing` of its previous parameters.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{CrossEntropyAgent, CrossEntropyParameters};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut agent: CrossEntropyAgent<f64> = CrossEntropyAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     CrossEntropyParameters::default(),
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+///
+/// let state = EnvironmentState::default();
+/// for _ in 0..20 {
+///     agent.reset().unwrap();
+///     let action = agent.choose_action(&state).unwrap();
+///     let reward = match action[&[0]] {
+///         DimensionValue::Float(value) => value,
+///         _ => panic!("expected a float"),
+///     };
+///     agent.process_reward(&state, &action, &state, reward, true).unwrap();
+/// }
+///
+/// assert_eq!(1, agent.iterations());
+/// ```
+pub struct CrossEntropyAgent<R: Reward + Into<f64>> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    hyperparameters: CrossEntropyParameters,
+    distributions: Vec<Vec<CrossEntropyDistribution>>,
+    evaluated: Vec<(f64, Vec<Vec<DimensionValue>>)>,
+    sequence: Vec<Vec<DimensionValue>>,
+    episode_return: f64,
+    episode_running: bool,
+    iterations: u64,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward + Into<f64>> CrossEntropyAgent<R> {
+    /// Creates a new CrossEntropyAgent with the provided ActionSpace, starting with distributions
+    /// covering the boundaries of every dimension.
+    pub fn with(
+        action_spaces: ActionSpace,
+        hyperparameters: CrossEntropyParameters,
+    ) -> Result<Self, RandomAgentError> {
+        if hyperparameters.population_size == 0 || hyperparameters.horizon == 0 {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: "population size and horizon have to be at least 1".to_string(),
+            });
+        }
+        if !(hyperparameters.elite_fraction > 0.0 && hyperparameters.elite_fraction <= 1.0) {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "elite fraction has to be within (0, 1], but is {}",
+                    hyperparameters.elite_fraction
+                ),
+            });
+        }
+        if !(0.0..1.0).contains(&hyperparameters.smoothing) {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "smoothing has to be within [0, 1), but is {}",
+                    hyperparameters.smoothing
+                ),
+            });
+        }
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        let distributions = vec![
+            boundaries
+                .iter()
+                .map(CrossEntropyDistribution::initial)
+                .collect();
+            hyperparameters.horizon
+        ];
+        Ok(Self {
+            action_spaces,
+            boundaries,
+            hyperparameters,
+            distributions,
+            evaluated: Vec::new(),
+            sequence: Vec::new(),
+            episode_return: 0.0,
+            episode_running: false,
+            iterations: 0,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns the distributions of every step within the horizon, each containing one
+    /// distribution per dimension in row-major order.
+    pub fn distributions(&self) -> &[Vec<CrossEntropyDistribution>] {
+        &self.distributions
+    }
+
+    /// Returns the number of refits done so far.
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    fn finish_episode(&mut self) {
+        if !self.episode_running {
+            return;
+        }
+        self.episode_running = false;
+        let sequence = std::mem::take(&mut self.sequence);
+        self.evaluated.push((self.episode_return, sequence));
+        if self.evaluated.len() == self.hyperparameters.population_size {
+            self.refit();
+        }
+    }
+
+    fn refit(&mut self) {
+        self.evaluated.sort_by(|(first, _), (second, _)| {
+            second
+                .partial_cmp(first)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let elite_count = ((self.hyperparameters.elite_fraction
+            * self.hyperparameters.population_size as f64)
+            .ceil() as usize)
+            .max(1);
+        let elite = &self.evaluated[..elite_count.min(self.evaluated.len())];
+        for (step, distributions) in self.distributions.iter_mut().enumerate() {
+            for (dimension, (distribution, boundaries)) in distributions
+                .iter_mut()
+                .zip(self.boundaries.iter())
+                .enumerate()
+            {
+                let values: Vec<DimensionValue> = elite
+                    .iter()
+                    .filter_map(|(_, sequence)| sequence.get(step))
+                    .map(|values| values[dimension].clone())
+                    .collect();
+                distribution.refit(boundaries, &values, self.hyperparameters.smoothing);
+            }
+        }
+        self.evaluated.clear();
+        self.iterations += 1;
+    }
+}
+
+impl<R: Reward + Into<f64>> Agent<RandomAgentError, R, CrossEntropyAgentStorage>
+    for CrossEntropyAgent<R>
+{
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        self.finish_episode();
+        self.sequence.clear();
+        self.episode_return = 0.0;
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let step = self.sequence.len().min(self.hyperparameters.horizon - 1);
+        let rng = &mut self.rng.rng;
+        let values: Vec<DimensionValue> = self.distributions[step]
+            .iter()
+            .zip(self.boundaries.iter())
+            .map(|(distribution, boundaries)| distribution.sample(boundaries, rng))
+            .collect();
+        if self.sequence.len() < self.hyperparameters.horizon {
+            self.sequence.push(values.clone());
+        }
+        Ok(util::compose_action(
+            self.action_spaces.dimensions(),
+            values,
+        ))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        reward: R,
+        done: bool,
+    ) -> Result<(), RandomAgentError> {
+        self.episode_return += reward.into();
+        self.episode_running = true;
+        if done {
+            self.finish_episode();
+        }
+        Ok(())
+    }
+
+    fn load(&mut self, data: CrossEntropyAgentStorage) -> Result<(), RandomAgentError> {
+        if data.distributions.len() != self.hyperparameters.horizon
+            || data.distributions.iter().any(|distributions| {
+                distributions.len() != self.boundaries.len()
+                    || distributions
+                        .iter()
+                        .zip(self.boundaries.iter())
+                        .any(|(distribution, boundaries)| !distribution.fits(boundaries))
+            })
+            || data.evaluated.len() >= self.hyperparameters.population_size
+            || data
+                .evaluated
+                .iter()
+                .map(|(_, sequence)| sequence)
+                .chain(std::iter::once(&data.sequence))
+                .any(|sequence| {
+                    sequence.len() > self.hyperparameters.horizon
+                        || sequence
+                            .iter()
+                            .any(|values| values.len() != self.boundaries.len())
+                })
+        {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: "stored distributions or sequences don't match the hyperparameters"
+                    .to_string(),
+            });
+        }
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.distributions = data.distributions;
+        self.evaluated = data.evaluated;
+        self.sequence = data.sequence;
+        self.episode_return = data.episode_return;
+        self.episode_running = data.episode_running;
+        self.iterations = data.iterations;
+        Ok(())
+    }
+
+    fn store(&self) -> CrossEntropyAgentStorage {
+        CrossEntropyAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            distributions: self.distributions.clone(),
+            evaluated: self.evaluated.clone(),
+            sequence: self.sequence.clone(),
+            episode_return: self.episode_return,
+            episode_running: self.episode_running,
+            iterations: self.iterations,
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CrossEntropyAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    distributions: Vec<Vec<CrossEntropyDistribution>>,
+    evaluated: Vec<(f64, Vec<Vec<DimensionValue>>)>,
+    sequence: Vec<Vec<DimensionValue>>,
+    episode_return: f64,
+    episode_running: bool,
+    iterations: u64,
+}
@@ -0,0 +1,239 @@
+//! Agent sampling discrete actions without replacement.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::DimensionBoundaries;
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::Rng;
+
+use serde::{Deserialize, Serialize};
+
+use crate::enumeration::{count_actions, nth_action};
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Describes when the deck of actions gets refilled besides running empty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DeckRefill {
+    /// The deck is refilled on every `Agent::reset`.
+    PerEpisode,
+    /// The deck is kept across episodes and only refilled once it is empty.
+    AcrossEpisodes,
+}
+
+/// Number of rounds of the Feistel network shuffling the deck.
+const FEISTEL_ROUNDS: u64 = 6;
+
+/// Returns the position of `index` within the deck shuffled with `key`.
+///
+/// The deck isn't materialized. Instead a keyed Feistel network permutes the smallest range of
+/// `2^(2h)` indices containing all `total` ones and indices outside of the deck are walked along
+/// their cycle until they fall into it again.
+pub(crate) fn shuffled_index(key: u64, total: u64, index: u64) -> u64 {
+    let mut half_bits = 1;
+    while 1u64 << (2 * half_bits) < total {
+        half_bits += 1;
+    }
+    let mask = (1u64 << half_bits) - 1;
+    let mut value = index;
+    loop {
+        let mut left = value >> half_bits;
+        let mut right = value & mask;
+        for round in 0..FEISTEL_ROUNDS {
+            let next = left ^ (mix(key, round, right) & mask);
+            left = right;
+            right = next;
+        }
+        value = (left << half_bits) | right;
+        if value < total {
+            return value;
+        }
+    }
+}
+
+/// Mixes the key, round and value into pseudo-random bits (SplitMix64 finalizer).
+fn mix(key: u64, round: u64, value: u64) -> u64 {
+    let mut bits =
+        key ^ round.wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ value.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    bits = (bits ^ (bits >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    bits = (bits ^ (bits >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    bits ^ (bits >> 31)
+}
+
+/// Agent which chooses his actions randomly, but doesn't repeat any action before all actions of
+/// the fully discrete ActionSpace have been chosen, like drawing cards from a shuffled deck.
+///
+/// The deck is shuffled by a pseudo-random permutation derived from a single key, so neither
+/// memory nor storage grow with the number of actions.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{DeckRandomAgent, DeckRefill};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: DeckRandomAgent<f64> = DeckRandomAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(0..=1)]),
+///     DeckRefill::AcrossEpisodes,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let first_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+/// let second_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_ne!(first_action, second_action);
+/// ```
+///
+/// Storing and loading resumes the exact trajectory:
+///
+/// ```
+/// use gymnarium_agents_random::{DeckRandomAgent, DeckRefill};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: DeckRandomAgent<f64> = DeckRandomAgent::with(
+///     ActionSpace::simple(vec![
+///         DimensionBoundaries::from(0..=3),
+///         DimensionBoundaries::from(0..=3),
+///     ]),
+///     DeckRefill::AcrossEpisodes,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+/// for _ in 0..5 {
+///     agent.choose_action(&EnvironmentState::default()).unwrap();
+/// }
+/// assert_eq!(11, agent.remaining());
+///
+/// let mut resumed_agent: DeckRandomAgent<f64> = DeckRandomAgent::with(
+///     ActionSpace::simple(vec![
+///         DimensionBoundaries::from(0..=3),
+///         DimensionBoundaries::from(0..=3),
+///     ]),
+///     DeckRefill::AcrossEpisodes,
+/// ).unwrap();
+/// resumed_agent.load(agent.store()).unwrap();
+///
+/// for _ in 0..20 {
+///     assert_eq!(
+///         agent.choose_action(&EnvironmentState::default()).unwrap(),
+///         resumed_agent.choose_action(&EnvironmentState::default()).unwrap(),
+///     );
+/// }
+/// ```
+pub struct DeckRandomAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    refill: DeckRefill,
+    total: u64,
+    deck_key: u64,
+    dealt: u64,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> DeckRandomAgent<R> {
+    /// Creates a new DeckRandomAgent with the provided ActionSpace, which has to consist of
+    /// integer boundaries only.
+    pub fn with(action_spaces: ActionSpace, refill: DeckRefill) -> Result<Self, RandomAgentError> {
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        let total = count_actions(&boundaries)?;
+        Ok(Self {
+            action_spaces,
+            boundaries,
+            refill,
+            total,
+            deck_key: 0,
+            dealt: total,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns the number of actions left in the deck.
+    pub fn remaining(&self) -> usize {
+        (self.total - self.dealt) as usize
+    }
+
+    fn refill_deck(&mut self) {
+        self.deck_key = self.rng.rng.gen();
+        self.dealt = 0;
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, DeckRandomAgentStorage> for DeckRandomAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        self.dealt = self.total;
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        if self.refill == DeckRefill::PerEpisode {
+            self.refill_deck();
+        }
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        if self.dealt >= self.total {
+            self.refill_deck();
+        }
+        let index = shuffled_index(self.deck_key, self.total, self.dealt);
+        self.dealt += 1;
+        Ok(util::compose_action(
+            self.action_spaces.dimensions(),
+            nth_action(&self.boundaries, index),
+        ))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: DeckRandomAgentStorage) -> Result<(), RandomAgentError> {
+        if data.dealt > self.total {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "stored deck has dealt {} actions, but there are only {} actions",
+                    data.dealt, self.total
+                ),
+            });
+        }
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.deck_key = data.deck_key;
+        self.dealt = data.dealt;
+        Ok(())
+    }
+
+    fn store(&self) -> DeckRandomAgentStorage {
+        DeckRandomAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            deck_key: self.deck_key,
+            dealt: self.dealt,
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DeckRandomAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    deck_key: u64,
+    dealt: u64,
+}
@@ -0,0 +1,200 @@
+//! Agent sampling a group of float dimensions from the simplex.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand_distr::{Dirichlet, Distribution};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Agent which fills a group of float dimensions with a sample of a Dirichlet distribution, so
+/// that they are not negative and sum up to one, e.g. for portfolio allocations.
+///
+/// All other dimensions are sampled uniformly.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::DirichletAgent;
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut agent: DirichletAgent<f64> = DirichletAgent::with(
+///     ActionSpace::simple(vec![
+///         DimensionBoundaries::from(0.0..=1.0),
+///         DimensionBoundaries::from(0.0..=1.0),
+///         DimensionBoundaries::from(0.0..=1.0),
+///     ]),
+///     vec![0, 1, 2],
+///     vec![1.0, 1.0, 1.0],
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let chosen_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// let sum: f64 = (0..3usize)
+///     .map(|index| match chosen_action[&[index]] {
+///         DimensionValue::Float(value) => value,
+///         _ => panic!("expected a float"),
+///     })
+///     .sum();
+/// assert!((sum - 1.0).abs() < 1e-9);
+/// ```
+pub struct DirichletAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    group: Vec<usize>,
+    dirichlet: Dirichlet<f64>,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> DirichletAgent<R> {
+    /// Creates a new DirichletAgent with the provided ActionSpace.
+    ///
+    /// `group` contains the row-major indices of the dimensions lying on the simplex and
+    /// `concentration` the positive concentration parameter of each of them. The group has to
+    /// consist of at least two float dimensions whose boundaries contain `[0, 1]`.
+    pub fn with(
+        action_spaces: ActionSpace,
+        group: Vec<usize>,
+        concentration: Vec<f64>,
+    ) -> Result<Self, RandomAgentError> {
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        if group.len() != concentration.len() {
+            return Err(RandomAgentError::ParameterCountMismatch {
+                expected: group.len(),
+                actual: concentration.len(),
+            });
+        }
+        if group.len() < 2 {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: "the simplex group has to contain at least two dimensions".to_string(),
+            });
+        }
+        for (position, dimension) in group.iter().enumerate() {
+            if group[..position].contains(dimension) {
+                return Err(RandomAgentError::InvalidConfiguration {
+                    reason: format!("dimension {} is part of the simplex group twice", dimension),
+                });
+            }
+            match boundaries.get(*dimension) {
+                Some(DimensionBoundaries::Float { minimum, maximum })
+                    if *minimum <= 0.0 && *maximum >= 1.0 => {}
+                Some(_) => {
+                    return Err(RandomAgentError::IncompatibleDistribution {
+                        dimension: *dimension,
+                        reason:
+                            "dirichlet distribution requires float boundaries containing [0, 1]"
+                                .to_string(),
+                    })
+                }
+                None => {
+                    return Err(RandomAgentError::InvalidConfiguration {
+                        reason: format!(
+                            "dimension {} doesn't exist, there are only {} dimensions",
+                            dimension,
+                            boundaries.len()
+                        ),
+                    })
+                }
+            }
+        }
+        for (dimension, alpha) in group.iter().zip(concentration.iter()) {
+            if !alpha.is_finite() || *alpha <= 0.0 {
+                return Err(RandomAgentError::InvalidDistributionParameter {
+                    dimension: *dimension,
+                    reason: format!(
+                        "concentration has to be finite and positive, but is {}",
+                        alpha
+                    ),
+                });
+            }
+        }
+        let dirichlet = Dirichlet::new(concentration).map_err(|error| {
+            RandomAgentError::InvalidConfiguration {
+                reason: format!("{:?}", error),
+            }
+        })?;
+        Ok(Self {
+            action_spaces,
+            boundaries,
+            group,
+            dirichlet,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, DirichletAgentStorage> for DirichletAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let mut simplex: Vec<Option<f64>> = vec![None; self.boundaries.len()];
+        for (dimension, value) in self
+            .group
+            .iter()
+            .zip(self.dirichlet.sample(&mut self.rng.rng).into_iter())
+        {
+            simplex[*dimension] = Some(value);
+        }
+        let mut values: Vec<DimensionValue> = Vec::with_capacity(self.boundaries.len());
+        for (boundaries, value) in self.boundaries.iter().zip(simplex.into_iter()) {
+            values.push(match value {
+                Some(value) => DimensionValue::Float(value),
+                None => util::sample_uniform(boundaries, &mut self.rng.rng),
+            });
+        }
+        Ok(util::compose_action(
+            self.action_spaces.dimensions(),
+            values,
+        ))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: DirichletAgentStorage) -> Result<(), RandomAgentError> {
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        Ok(())
+    }
+
+    fn store(&self) -> DirichletAgentStorage {
+        DirichletAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DirichletAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+}
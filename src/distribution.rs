@@ -0,0 +1,282 @@
+//! Per-dimension distributions used by `RandomAgent` instead of the uniform default.
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+
+use rand::distributions::WeightedIndex;
+use rand::Rng;
+
+use rand_distr::{Beta, Distribution, Exp, Normal, StandardNormal};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::sample_uniform;
+use crate::RandomAgentError;
+
+/// Describes what happens to a sampled value which lies outside its boundaries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OutOfBoundsHandling {
+    /// Clamp the value onto the nearest boundary.
+    Clip,
+    /// Draw again until the value lies within the boundaries.
+    ///
+    /// After `maximum_attempts` unsuccessful draws the last value is clipped.
+    Resample { maximum_attempts: usize },
+}
+
+/// Distribution a single dimension of the `ActionSpace` is sampled from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DimensionDistribution {
+    /// Uniform draw within the boundaries, just like `ActionSpace::sample_with`.
+    Uniform,
+    /// Normal distribution for float dimensions.
+    Gaussian {
+        mean: f64,
+        standard_deviation: f64,
+        out_of_bounds: OutOfBoundsHandling,
+    },
+    /// Weighted draw for integer dimensions.
+    ///
+    /// Contains one weight per integer within the boundaries, starting at the minimum. The
+    /// weights don't have to sum up to one, e.g. `vec![5.0, 1.0, 1.0, 1.0, 1.0, 1.0]` chooses the
+    /// minimum half of the time and the remaining five integers uniformly otherwise.
+    Categorical { weights: Vec<f64> },
+    /// Normal distribution for float dimensions truncated to the boundaries.
+    ///
+    /// Unlike `Gaussian` with clipping, no probability mass piles up on the boundaries.
+    TruncatedNormal { mean: f64, standard_deviation: f64 },
+    /// Beta distribution for float dimensions scaled from `[0, 1]` onto the boundaries.
+    ///
+    /// `alpha` and `beta` above one bias towards the centre, below one towards the extremes.
+    Beta { alpha: f64, beta: f64 },
+}
+
+impl DimensionDistribution {
+    /// Checks whether this distribution can be used for the given boundaries.
+    pub(crate) fn validate(
+        &self,
+        dimension: usize,
+        boundaries: &DimensionBoundaries,
+    ) -> Result<(), RandomAgentError> {
+        match (self, boundaries) {
+            (Self::Uniform, _) => Ok(()),
+            (
+                Self::Gaussian {
+                    mean,
+                    standard_deviation,
+                    ..
+                },
+                DimensionBoundaries::Float { .. },
+            ) => {
+                if mean.is_finite() && standard_deviation.is_finite() && *standard_deviation >= 0.0
+                {
+                    Ok(())
+                } else {
+                    Err(RandomAgentError::InvalidDistributionParameter {
+                        dimension,
+                        reason: format!(
+                            "mean has to be finite and standard deviation finite and not negative, but are {} and {}",
+                            mean, standard_deviation
+                        ),
+                    })
+                }
+            }
+            (Self::Gaussian { .. }, DimensionBoundaries::Integer { .. }) => {
+                Err(RandomAgentError::IncompatibleDistribution {
+                    dimension,
+                    reason: "gaussian distribution requires float boundaries".to_string(),
+                })
+            }
+            (Self::Categorical { weights }, DimensionBoundaries::Integer { minimum, maximum }) => {
+                let expected = (*maximum as i128 - *minimum as i128 + 1) as u128;
+                if weights.len() as u128 != expected {
+                    Err(RandomAgentError::WeightCountMismatch {
+                        dimension,
+                        expected,
+                        actual: weights.len(),
+                    })
+                } else if weights
+                    .iter()
+                    .any(|weight| !weight.is_finite() || *weight < 0.0)
+                    || weights.iter().sum::<f64>() <= 0.0
+                {
+                    Err(RandomAgentError::InvalidDistributionParameter {
+                        dimension,
+                        reason: "weights have to be finite, not negative and not all zero"
+                            .to_string(),
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            (Self::Categorical { .. }, DimensionBoundaries::Float { .. }) => {
+                Err(RandomAgentError::IncompatibleDistribution {
+                    dimension,
+                    reason: "categorical distribution requires integer boundaries".to_string(),
+                })
+            }
+            (
+                Self::TruncatedNormal {
+                    mean,
+                    standard_deviation,
+                },
+                DimensionBoundaries::Float { minimum, maximum },
+            ) => {
+                if !(minimum.is_finite() && maximum.is_finite()) {
+                    Err(RandomAgentError::IncompatibleDistribution {
+                        dimension,
+                        reason: "truncated normal distribution requires finite boundaries"
+                            .to_string(),
+                    })
+                } else if !mean.is_finite()
+                    || !standard_deviation.is_finite()
+                    || *standard_deviation <= 0.0
+                {
+                    Err(RandomAgentError::InvalidDistributionParameter {
+                        dimension,
+                        reason: format!(
+                            "mean has to be finite and standard deviation finite and positive, but are {} and {}",
+                            mean, standard_deviation
+                        ),
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            (Self::Beta { alpha, beta }, DimensionBoundaries::Float { minimum, maximum }) => {
+                if !(minimum.is_finite() && maximum.is_finite()) {
+                    Err(RandomAgentError::IncompatibleDistribution {
+                        dimension,
+                        reason: "beta distribution requires finite boundaries".to_string(),
+                    })
+                } else if !alpha.is_finite() || !beta.is_finite() || *alpha <= 0.0 || *beta <= 0.0 {
+                    Err(RandomAgentError::InvalidDistributionParameter {
+                        dimension,
+                        reason: format!(
+                            "alpha and beta have to be finite and positive, but are {} and {}",
+                            alpha, beta
+                        ),
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            (Self::TruncatedNormal { .. }, DimensionBoundaries::Integer { .. })
+            | (Self::Beta { .. }, DimensionBoundaries::Integer { .. }) => {
+                Err(RandomAgentError::IncompatibleDistribution {
+                    dimension,
+                    reason: "truncated normal and beta distributions require float boundaries"
+                        .to_string(),
+                })
+            }
+        }
+    }
+
+    /// Draws a value within the boundaries.
+    ///
+    /// Expects `validate` to have succeeded for these boundaries.
+    pub(crate) fn sample<G: Rng>(
+        &self,
+        boundaries: &DimensionBoundaries,
+        rng: &mut G,
+    ) -> DimensionValue {
+        match (self, boundaries) {
+            (
+                Self::Gaussian {
+                    mean,
+                    standard_deviation,
+                    out_of_bounds,
+                },
+                DimensionBoundaries::Float { minimum, maximum },
+            ) => {
+                let normal = Normal::new(*mean, *standard_deviation)
+                    .expect("standard deviation got validated before");
+                let mut value = normal.sample(rng);
+                if let OutOfBoundsHandling::Resample { maximum_attempts } = out_of_bounds {
+                    let mut attempts = 1;
+                    while !(*minimum..=*maximum).contains(&value) && attempts < *maximum_attempts {
+                        value = normal.sample(rng);
+                        attempts += 1;
+                    }
+                }
+                DimensionValue::Float(value.max(*minimum).min(*maximum))
+            }
+            (Self::Categorical { weights }, DimensionBoundaries::Integer { minimum, .. }) => {
+                let index = WeightedIndex::new(weights)
+                    .expect("weights got validated before")
+                    .sample(rng);
+                DimensionValue::Integer(*minimum + index as i64)
+            }
+            (
+                Self::TruncatedNormal {
+                    mean,
+                    standard_deviation,
+                },
+                DimensionBoundaries::Float { minimum, maximum },
+            ) => {
+                let lower = (*minimum - *mean) / *standard_deviation;
+                let upper = (*maximum - *mean) / *standard_deviation;
+                let value =
+                    *mean + *standard_deviation * standard_truncated_normal(lower, upper, rng);
+                DimensionValue::Float(value.max(*minimum).min(*maximum))
+            }
+            (Self::Beta { alpha, beta }, DimensionBoundaries::Float { minimum, maximum }) => {
+                let unit = Beta::new(*alpha, *beta)
+                    .expect("alpha and beta got validated before")
+                    .sample(rng);
+                DimensionValue::Float(*minimum + unit * (*maximum - *minimum))
+            }
+            _ => sample_uniform(boundaries, rng),
+        }
+    }
+}
+
+/// Draws from the standard normal distribution truncated to `[lower, upper]`.
+///
+/// This is the exact accept-reject algorithm of Robert (1995), "Simulation of truncated normal
+/// variables", choosing between normal, uniform and exponential proposals depending on the
+/// interval.
+fn standard_truncated_normal<G: Rng>(lower: f64, upper: f64, rng: &mut G) -> f64 {
+    if lower >= upper {
+        return lower;
+    }
+    if upper <= 0.0 {
+        return -standard_truncated_normal(-upper, -lower, rng);
+    }
+    if lower <= 0.0 {
+        if upper - lower >= (2.0 * std::f64::consts::PI).sqrt() {
+            loop {
+                let value: f64 = StandardNormal.sample(rng);
+                if (lower..=upper).contains(&value) {
+                    return value;
+                }
+            }
+        }
+        loop {
+            let value = rng.gen_range(lower, upper);
+            if rng.gen::<f64>() <= (-value * value / 2.0).exp() {
+                return value;
+            }
+        }
+    }
+    let optimal_rate = (lower + (lower * lower + 4.0).sqrt()) / 2.0;
+    let uniform_threshold = lower
+        + 2.0 * std::f64::consts::E.sqrt() / (lower + (lower * lower + 4.0).sqrt())
+            * ((lower * lower - lower * (lower * lower + 4.0).sqrt()) / 4.0).exp();
+    if upper <= uniform_threshold {
+        loop {
+            let value = rng.gen_range(lower, upper);
+            if rng.gen::<f64>() <= ((lower * lower - value * value) / 2.0).exp() {
+                return value;
+            }
+        }
+    }
+    let exponential = Exp::new(optimal_rate).expect("rate is positive");
+    loop {
+        let value = lower + exponential.sample(rng);
+        if value <= upper
+            && rng.gen::<f64>() <= (-(value - optimal_rate) * (value - optimal_rate) / 2.0).exp()
+        {
+            return value;
+        }
+    }
+}
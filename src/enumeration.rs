@@ -0,0 +1,220 @@
+//! Agent enumerating every action of a discrete action space.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::Rng;
+
+use serde::{Deserialize, Serialize};
+
+use crate::deck::shuffled_index;
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Maximum number of actions an ActionSpace may contain to be enumerated.
+pub const MAXIMUM_ENUMERATED_ACTIONS: u64 = 1 << 24;
+
+/// Order in which the actions are enumerated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EnumerationOrder {
+    /// Row-major order of the dimensions, the last dimension changing fastest.
+    Lexicographic,
+    /// Random order depending on the seed. The order is a pseudo-random permutation derived from
+    /// a single key, so it isn't materialized.
+    Shuffled,
+}
+
+/// Returns the number of actions within the integer boundaries.
+///
+/// Fails if any boundaries are float boundaries or if there are more than
+/// `MAXIMUM_ENUMERATED_ACTIONS` actions.
+pub(crate) fn count_actions(boundaries: &[DimensionBoundaries]) -> Result<u64, RandomAgentError> {
+    let mut count: u64 = 1;
+    for (dimension, boundaries) in boundaries.iter().enumerate() {
+        match boundaries {
+            DimensionBoundaries::Integer { minimum, maximum } => {
+                let values = (*maximum as i128 - *minimum as i128 + 1) as u128;
+                count = match (count as u128).checked_mul(values) {
+                    Some(count) if count <= MAXIMUM_ENUMERATED_ACTIONS as u128 => count as u64,
+                    _ => {
+                        return Err(RandomAgentError::TooManyActions {
+                            maximum: MAXIMUM_ENUMERATED_ACTIONS,
+                        })
+                    }
+                };
+            }
+            DimensionBoundaries::Float { .. } => {
+                return Err(RandomAgentError::NotEnumerable { dimension })
+            }
+        }
+    }
+    Ok(count)
+}
+
+/// Returns the action with the given lexicographic index within the integer boundaries.
+pub(crate) fn nth_action(
+    boundaries: &[DimensionBoundaries],
+    mut index: u64,
+) -> Vec<DimensionValue> {
+    let mut values = vec![DimensionValue::Integer(0); boundaries.len()];
+    for (value, boundaries) in values.iter_mut().zip(boundaries.iter()).rev() {
+        if let DimensionBoundaries::Integer { minimum, maximum } = boundaries {
+            let width = (*maximum - *minimum) as u64 + 1;
+            *value = DimensionValue::Integer(*minimum + (index % width) as i64);
+            index /= width;
+        }
+    }
+    values
+}
+
+/// Agent which chooses every action of a fully discrete ActionSpace exactly once.
+///
+/// The actions are enumerated across episodes. After all of them have been chosen every further
+/// `choose_action` fails with `RandomAgentError::BudgetExhausted` until the agent gets reseeded.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{EnumerationAgent, EnumerationOrder};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut agent: EnumerationAgent<f64> = EnumerationAgent::with(
+///     ActionSpace::simple(vec![
+///         DimensionBoundaries::from(0..=1),
+///         DimensionBoundaries::from(0..=2),
+///     ]),
+///     EnumerationOrder::Lexicographic,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// assert_eq!(6, agent.total());
+/// let first_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+/// assert_eq!(DimensionValue::Integer(0), first_action[&[0]]);
+/// assert_eq!(DimensionValue::Integer(0), first_action[&[1]]);
+/// assert_eq!(1, agent.visited());
+/// ```
+pub struct EnumerationAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    order: EnumerationOrder,
+    total: u64,
+    shuffle_key: u64,
+    visited: u64,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> EnumerationAgent<R> {
+    /// Creates a new EnumerationAgent with the provided ActionSpace, which has to consist of
+    /// integer boundaries only.
+    pub fn with(
+        action_spaces: ActionSpace,
+        order: EnumerationOrder,
+    ) -> Result<Self, RandomAgentError> {
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        let total = count_actions(&boundaries)?;
+        let mut agent = Self {
+            action_spaces,
+            boundaries,
+            order,
+            total,
+            shuffle_key: 0,
+            visited: 0,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        };
+        agent.shuffle();
+        Ok(agent)
+    }
+
+    /// Returns the number of actions within the ActionSpace.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the number of actions which have already been chosen.
+    pub fn visited(&self) -> u64 {
+        self.visited
+    }
+
+    fn shuffle(&mut self) {
+        self.shuffle_key = self.rng.rng.gen();
+        self.visited = 0;
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, EnumerationAgentStorage> for EnumerationAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        self.shuffle();
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        if self.visited >= self.total {
+            return Err(RandomAgentError::BudgetExhausted { budget: self.total });
+        }
+        let index = match self.order {
+            EnumerationOrder::Lexicographic => self.visited,
+            EnumerationOrder::Shuffled => {
+                shuffled_index(self.shuffle_key, self.total, self.visited)
+            }
+        };
+        self.visited += 1;
+        Ok(util::compose_action(
+            self.action_spaces.dimensions(),
+            nth_action(&self.boundaries, index),
+        ))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: EnumerationAgentStorage) -> Result<(), RandomAgentError> {
+        if data.visited > self.total {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "stored agent has visited {} actions, but there are only {} actions",
+                    data.visited, self.total
+                ),
+            });
+        }
+        self.rng.restore(data.last_seed, 0);
+        self.shuffle();
+        self.visited = data.visited;
+        Ok(())
+    }
+
+    fn store(&self) -> EnumerationAgentStorage {
+        EnumerationAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            visited: self.visited,
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EnumerationAgentStorage {
+    last_seed: Seed,
+    visited: u64,
+}
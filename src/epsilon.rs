@@ -0,0 +1,280 @@
+//! Wrapper replacing the actions of another agent with random ones (epsilon-greedy exploration).
+
+use std::marker::PhantomData;
+
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::Rng;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::util::SeededRng;
+use crate::{RandomAgentError, WrappedAgentError};
+
+/// Describes how the probability of a random action develops over the chosen actions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EpsilonSchedule {
+    /// Epsilon stays the same.
+    Constant(f64),
+    /// Epsilon decreases linearly from `start` to `end` within `steps` actions and stays at `end`
+    /// afterwards.
+    LinearDecay { start: f64, end: f64, steps: u64 },
+    /// Epsilon is `end + (start - end) * decay^step` for the `step`th action.
+    ExponentialDecay { start: f64, end: f64, decay: f64 },
+}
+
+impl EpsilonSchedule {
+    /// Returns epsilon for the action with the given index.
+    pub fn epsilon(&self, step: u64) -> f64 {
+        match self {
+            Self::Constant(epsilon) => *epsilon,
+            Self::LinearDecay { start, end, steps } => {
+                if step >= *steps {
+                    *end
+                } else {
+                    start + (end - start) * (step as f64 / *steps as f64)
+                }
+            }
+            Self::ExponentialDecay { start, end, decay } => {
+                end + (start - end) * decay.powf(step as f64)
+            }
+        }
+    }
+
+    fn validate(&self) -> Result<(), RandomAgentError> {
+        let is_probability = |value: &f64| (0.0..=1.0).contains(value);
+        let valid = match self {
+            Self::Constant(epsilon) => is_probability(epsilon),
+            Self::LinearDecay { start, end, .. } => is_probability(start) && is_probability(end),
+            Self::ExponentialDecay { start, end, decay } => {
+                is_probability(start) && is_probability(end) && is_probability(decay)
+            }
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "epsilon values and decay have to be between 0 and 1, but schedule is {:?}",
+                    self
+                ),
+            })
+        }
+    }
+}
+
+/// Agent wrapping another agent, whose actions are replaced with probability epsilon by actions
+/// sampled like `RandomAgent` does.
+///
+/// Rewards, resets, reseeds, storing, loading and closing are forwarded to the inner agent. The
+/// inner agent is told about the action which actually got executed.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{EpsilonRandom, EpsilonSchedule, RandomAgent};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let action_space = ActionSpace::simple(vec![DimensionBoundaries::from(0..=3)]);
+/// let inner_agent: RandomAgent<f64> = RandomAgent::with(action_space.clone());
+/// let mut agent = EpsilonRandom::with(
+///     inner_agent,
+///     action_space,
+///     EpsilonSchedule::LinearDecay { start: 1.0, end: 0.05, steps: 1_000 },
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let chosen_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_eq!(&vec![1], chosen_action.dimensions());
+/// assert!(agent.epsilon() < 1.0);
+/// ```
+pub struct EpsilonRandom<A, E, R, S>
+where
+    A: Agent<E, R, S>,
+    E: std::error::Error,
+    R: Reward,
+    S: Serialize + DeserializeOwned,
+{
+    inner: A,
+    action_spaces: ActionSpace,
+    schedule: EpsilonSchedule,
+    steps: u64,
+    rng: SeededRng,
+    _phantom_data: PhantomData<(E, R, S)>,
+}
+
+impl<A, E, R, S> EpsilonRandom<A, E, R, S>
+where
+    A: Agent<E, R, S>,
+    E: std::error::Error,
+    R: Reward,
+    S: Serialize + DeserializeOwned,
+{
+    /// Creates a new EpsilonRandom around the inner agent sampling random actions from the
+    /// provided ActionSpace.
+    pub fn with(
+        inner: A,
+        action_spaces: ActionSpace,
+        schedule: EpsilonSchedule,
+    ) -> Result<Self, RandomAgentError> {
+        schedule.validate()?;
+        Ok(Self {
+            inner,
+            action_spaces,
+            schedule,
+            steps: 0,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns epsilon for the next action.
+    pub fn epsilon(&self) -> f64 {
+        self.schedule.epsilon(self.steps)
+    }
+
+    /// Returns the wrapped agent.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Returns the wrapped agent mutably.
+    pub fn inner_mut(&mut self) -> &mut A {
+        &mut self.inner
+    }
+
+    /// Consumes the wrapper and returns the wrapped agent.
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+impl<A, E, R, S> Agent<WrappedAgentError<E>, R, EpsilonRandomStorage<S>>
+    for EpsilonRandom<A, E, R, S>
+where
+    A: Agent<E, R, S>,
+    E: std::error::Error,
+    R: Reward,
+    S: Serialize + DeserializeOwned,
+{
+    /// Reseeds this wrapper and the inner agent with a seed drawn from the wrapper.
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), WrappedAgentError<E>> {
+        self.rng.reseed(random_seed);
+        let inner_seed = self.rng.derive_seed();
+        self.inner
+            .reseed(Some(inner_seed))
+            .map_err(WrappedAgentError::Inner)
+    }
+
+    fn reset(&mut self) -> Result<(), WrappedAgentError<E>> {
+        self.inner.reset().map_err(WrappedAgentError::Inner)
+    }
+
+    fn choose_action(
+        &mut self,
+        state: &EnvironmentState,
+    ) -> Result<AgentAction, WrappedAgentError<E>> {
+        let inner_action = self
+            .inner
+            .choose_action(state)
+            .map_err(WrappedAgentError::Inner)?;
+        let epsilon = self.epsilon();
+        self.steps = self.steps.saturating_add(1);
+        if self.rng.rng.gen_bool(epsilon) {
+            Ok(self.action_spaces.sample_with(&mut self.rng.rng))
+        } else {
+            Ok(inner_action)
+        }
+    }
+
+    fn process_reward(
+        &mut self,
+        old_state: &EnvironmentState,
+        last_action: &AgentAction,
+        new_state: &EnvironmentState,
+        reward: R,
+        is_done: bool,
+    ) -> Result<(), WrappedAgentError<E>> {
+        self.inner
+            .process_reward(old_state, last_action, new_state, reward, is_done)
+            .map_err(WrappedAgentError::Inner)
+    }
+
+    fn load(&mut self, data: EpsilonRandomStorage<S>) -> Result<(), WrappedAgentError<E>> {
+        self.inner
+            .load(data.inner)
+            .map_err(WrappedAgentError::Inner)?;
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.steps = data.steps;
+        Ok(())
+    }
+
+    fn store(&self) -> EpsilonRandomStorage<S> {
+        EpsilonRandomStorage {
+            inner: self.inner.store(),
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            steps: self.steps,
+        }
+    }
+
+    fn close(&mut self) -> Result<(), WrappedAgentError<E>> {
+        self.inner.close().map_err(WrappedAgentError::Inner)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EpsilonRandomStorage<S> {
+    inner: S,
+    last_seed: Seed,
+    rng_word_pos: u128,
+    steps: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+    use gymnarium_base::{ActionSpace, Agent, EnvironmentState, Seed};
+
+    use crate::{EpsilonRandom, EpsilonSchedule, RandomAgent};
+
+    #[test]
+    fn exploration_is_independent_of_inner_action() {
+        let action_space = ActionSpace::simple(vec![DimensionBoundaries::from(0..=3)]);
+        let mut agent = EpsilonRandom::with(
+            RandomAgent::<f64>::with(action_space.clone()),
+            action_space.clone(),
+            EpsilonSchedule::Constant(0.25),
+        )
+        .unwrap();
+        agent.reseed(Some(Seed::from(0))).unwrap();
+        agent.reset().unwrap();
+
+        let mut mirror: RandomAgent<f64> = RandomAgent::with(action_space);
+        let mut chosen = [0u32; 4];
+        let mut replaced = [0u32; 4];
+        for _ in 0..4_000 {
+            mirror.load(agent.inner().store()).unwrap();
+            let inner_action = mirror.choose_action(&EnvironmentState::default()).unwrap();
+            let action = agent.choose_action(&EnvironmentState::default()).unwrap();
+            let index = match inner_action[&[0]] {
+                DimensionValue::Integer(value) => value as usize,
+                _ => panic!("expected an integer"),
+            };
+            chosen[index] += 1;
+            if action != inner_action {
+                replaced[index] += 1;
+            }
+        }
+
+        // A random action replaces the inner one with probability 0.25 * 3 / 4 for every value.
+        for (chosen, replaced) in chosen.iter().zip(replaced.iter()) {
+            let rate = f64::from(*replaced) / f64::from(*chosen);
+            assert!((0.1..0.3).contains(&rate), "replacement rate {}", rate);
+        }
+    }
+}
@@ -0,0 +1,299 @@
+//! Agent biased towards the edge cases of the action space for robustness testing.
+
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::Rng;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Kind of value a FuzzAgent emitted for a dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum FuzzCase {
+    /// The minimum of the boundaries.
+    Minimum,
+    /// The maximum of the boundaries.
+    Maximum,
+    /// The smallest value above the minimum (the next float or integer).
+    JustAboveMinimum,
+    /// The largest value below the maximum (the previous float or integer).
+    JustBelowMaximum,
+    /// Zero, if it lies within the boundaries.
+    Zero,
+    /// The middle of the boundaries, rounded down for integers.
+    Midpoint,
+    /// A uniformly sampled value.
+    Uniform,
+}
+
+/// Probabilities of the edge cases a FuzzAgent emits per dimension.
+///
+/// The remaining probability up to one is used for uniform samples. Cases which don't exist for
+/// some boundaries (e.g. zero outside of them) are replaced by uniform samples as well. Infinite
+/// float boundaries get values normally distributed around their finite end (or zero) instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FuzzProbabilities {
+    pub minimum: f64,
+    pub maximum: f64,
+    pub just_above_minimum: f64,
+    pub just_below_maximum: f64,
+    pub zero: f64,
+    pub midpoint: f64,
+}
+
+impl Default for FuzzProbabilities {
+    fn default() -> Self {
+        Self {
+            minimum: 0.1,
+            maximum: 0.1,
+            just_above_minimum: 0.05,
+            just_below_maximum: 0.05,
+            zero: 0.05,
+            midpoint: 0.05,
+        }
+    }
+}
+
+impl FuzzProbabilities {
+    fn cases(&self) -> [(FuzzCase, f64); 6] {
+        [
+            (FuzzCase::Minimum, self.minimum),
+            (FuzzCase::Maximum, self.maximum),
+            (FuzzCase::JustAboveMinimum, self.just_above_minimum),
+            (FuzzCase::JustBelowMaximum, self.just_below_maximum),
+            (FuzzCase::Zero, self.zero),
+            (FuzzCase::Midpoint, self.midpoint),
+        ]
+    }
+}
+
+/// Returns the value of the edge case within the boundaries or `None` if it doesn't exist.
+fn edge_value(case: FuzzCase, boundaries: &DimensionBoundaries) -> Option<DimensionValue> {
+    match boundaries {
+        DimensionBoundaries::Integer { minimum, maximum } => {
+            let value = match case {
+                FuzzCase::Minimum => *minimum,
+                FuzzCase::Maximum => *maximum,
+                FuzzCase::JustAboveMinimum => minimum.checked_add(1)?,
+                FuzzCase::JustBelowMaximum => maximum.checked_sub(1)?,
+                FuzzCase::Zero => 0,
+                FuzzCase::Midpoint => (*minimum as i128 + *maximum as i128).div_euclid(2) as i64,
+                FuzzCase::Uniform => return None,
+            };
+            if (*minimum..=*maximum).contains(&value) {
+                Some(DimensionValue::Integer(value))
+            } else {
+                None
+            }
+        }
+        DimensionBoundaries::Float { minimum, maximum } => {
+            let value = match case {
+                FuzzCase::Minimum => *minimum,
+                FuzzCase::Maximum => *maximum,
+                FuzzCase::JustAboveMinimum => next_float_towards(*minimum, *maximum)?,
+                FuzzCase::JustBelowMaximum => next_float_towards(*maximum, *minimum)?,
+                FuzzCase::Zero => 0.0,
+                FuzzCase::Midpoint => minimum / 2.0 + maximum / 2.0,
+                FuzzCase::Uniform => return None,
+            };
+            if (*minimum..=*maximum).contains(&value) {
+                Some(DimensionValue::Float(value))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Returns the next representable float after `value` in the direction of `target` or `None` if
+/// there is none, because `value` equals `target` or isn't finite.
+fn next_float_towards(value: f64, target: f64) -> Option<f64> {
+    if !value.is_finite() || value == target {
+        None
+    } else if value == 0.0 {
+        Some(f64::from_bits(1).copysign(target))
+    } else if (value < target) == (value > 0.0) {
+        Some(f64::from_bits(value.to_bits() + 1))
+    } else {
+        Some(f64::from_bits(value.to_bits() - 1))
+    }
+}
+
+/// Agent which emits the edge cases of every dimension with configurable probabilities and
+/// samples uniformly otherwise, for smoke-testing environments.
+///
+/// It keeps count of the emitted cases per dimension, so test harnesses can assert that every
+/// edge case has been covered.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{FuzzAgent, FuzzCase, FuzzProbabilities};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: FuzzAgent<f64> = FuzzAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     FuzzProbabilities::default(),
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// for _ in 0..1_000 {
+///     agent.choose_action(&EnvironmentState::default()).unwrap();
+/// }
+///
+/// assert!(agent.has_emitted(0, FuzzCase::Minimum));
+/// assert!(agent.has_emitted(0, FuzzCase::Maximum));
+/// ```
+pub struct FuzzAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    probabilities: FuzzProbabilities,
+    emitted: Vec<BTreeMap<FuzzCase, u64>>,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> FuzzAgent<R> {
+    /// Creates a new FuzzAgent with the provided ActionSpace and probabilities of edge cases.
+    pub fn with(
+        action_spaces: ActionSpace,
+        probabilities: FuzzProbabilities,
+    ) -> Result<Self, RandomAgentError> {
+        let cases = probabilities.cases();
+        if cases
+            .iter()
+            .any(|(_, probability)| !(0.0..=1.0).contains(probability))
+            || cases
+                .iter()
+                .map(|(_, probability)| probability)
+                .sum::<f64>()
+                > 1.0
+        {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "fuzz probabilities have to be between 0 and 1 and sum up to at most 1, but are {:?}",
+                    probabilities
+                ),
+            });
+        }
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        Ok(Self {
+            emitted: vec![BTreeMap::new(); boundaries.len()],
+            action_spaces,
+            boundaries,
+            probabilities,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns per dimension in row-major order how often each case has been emitted.
+    pub fn emitted(&self) -> &[BTreeMap<FuzzCase, u64>] {
+        &self.emitted
+    }
+
+    /// Returns whether the case has been emitted at least once for the dimension.
+    pub fn has_emitted(&self, dimension: usize, case: FuzzCase) -> bool {
+        matches!(
+            self.emitted.get(dimension).and_then(|cases| cases.get(&case)),
+            Some(count) if *count > 0
+        )
+    }
+
+    /// Forgets all emitted cases.
+    pub fn clear_emitted(&mut self) {
+        for cases in &mut self.emitted {
+            cases.clear();
+        }
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, FuzzAgentStorage> for FuzzAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let cases = self.probabilities.cases();
+        let mut values = Vec::with_capacity(self.boundaries.len());
+        for (boundaries, emitted) in self.boundaries.iter().zip(self.emitted.iter_mut()) {
+            let mut draw: f64 = self.rng.rng.gen();
+            let mut chosen = FuzzCase::Uniform;
+            for (case, probability) in cases.iter() {
+                if draw < *probability {
+                    chosen = *case;
+                    break;
+                }
+                draw -= probability;
+            }
+            let (case, value) = match edge_value(chosen, boundaries) {
+                Some(value) => (chosen, value),
+                None => (
+                    FuzzCase::Uniform,
+                    util::sample_valid(boundaries, &mut self.rng.rng),
+                ),
+            };
+            *emitted.entry(case).or_insert(0) += 1;
+            values.push(value);
+        }
+        Ok(util::compose_action(
+            self.action_spaces.dimensions(),
+            values,
+        ))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: FuzzAgentStorage) -> Result<(), RandomAgentError> {
+        if data.emitted.len() != self.boundaries.len() {
+            return Err(RandomAgentError::ParameterCountMismatch {
+                expected: self.boundaries.len(),
+                actual: data.emitted.len(),
+            });
+        }
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.emitted = data.emitted;
+        Ok(())
+    }
+
+    fn store(&self) -> FuzzAgentStorage {
+        FuzzAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            emitted: self.emitted.clone(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FuzzAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    emitted: Vec<BTreeMap<FuzzCase, u64>>,
+}
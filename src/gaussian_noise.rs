@@ -0,0 +1,233 @@
+//! Wrapper adding gaussian noise to the actions of another agent.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::Rng;
+
+use rand_distr::{Distribution, Normal};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::util::{self, SeededRng};
+use crate::{RandomAgentError, WrappedAgentError};
+
+/// Agent wrapping another agent, whose float actions are perturbed by gaussian noise.
+///
+/// Every float value of the inner action gets zero-mean noise with the standard deviation of its
+/// dimension added and is clamped back into the boundaries of the ActionSpace. Integer values are
+/// left alone unless `integer_step_probability` is set, in which case they are moved one step up
+/// or down with that probability (and clamped as well).
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{GaussianActionNoise, RandomAgent};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let action_space = ActionSpace::simple(vec![
+///     DimensionBoundaries::from(0..=3),
+///     DimensionBoundaries::from(-1.0..=1.0),
+/// ]);
+/// let inner_agent: RandomAgent<f64> = RandomAgent::with(action_space.clone());
+/// let mut agent = GaussianActionNoise::with(
+///     inner_agent,
+///     action_space,
+///     vec![0.0, 0.1],
+///     Some(0.1),
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let chosen_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_eq!(&vec![2], chosen_action.dimensions());
+/// ```
+pub struct GaussianActionNoise<A, E, R, S>
+where
+    A: Agent<E, R, S>,
+    E: std::error::Error,
+    R: Reward,
+    S: Serialize + DeserializeOwned,
+{
+    inner: A,
+    boundaries: Vec<DimensionBoundaries>,
+    noise: Vec<Normal<f64>>,
+    integer_step_probability: Option<f64>,
+    rng: SeededRng,
+    _phantom_data: PhantomData<(E, R, S)>,
+}
+
+impl<A, E, R, S> GaussianActionNoise<A, E, R, S>
+where
+    A: Agent<E, R, S>,
+    E: std::error::Error,
+    R: Reward,
+    S: Serialize + DeserializeOwned,
+{
+    /// Creates a new GaussianActionNoise around the inner agent.
+    ///
+    /// `standard_deviations` contains one entry per dimension of the ActionSpace in row-major
+    /// order; entries of integer dimensions are ignored.
+    pub fn with(
+        inner: A,
+        action_spaces: ActionSpace,
+        standard_deviations: Vec<f64>,
+        integer_step_probability: Option<f64>,
+    ) -> Result<Self, RandomAgentError> {
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        if boundaries.len() != standard_deviations.len() {
+            return Err(RandomAgentError::ParameterCountMismatch {
+                expected: boundaries.len(),
+                actual: standard_deviations.len(),
+            });
+        }
+        let mut noise = Vec::with_capacity(standard_deviations.len());
+        for (dimension, standard_deviation) in standard_deviations.into_iter().enumerate() {
+            if !standard_deviation.is_finite() || standard_deviation < 0.0 {
+                return Err(RandomAgentError::InvalidDistributionParameter {
+                    dimension,
+                    reason: format!(
+                        "standard deviation has to be finite and not negative, but is {}",
+                        standard_deviation
+                    ),
+                });
+            }
+            noise.push(
+                Normal::new(0.0, standard_deviation)
+                    .expect("standard deviation got validated before"),
+            );
+        }
+        if let Some(probability) = integer_step_probability {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(RandomAgentError::InvalidConfiguration {
+                    reason: format!(
+                        "integer step probability has to be between 0 and 1, but is {}",
+                        probability
+                    ),
+                });
+            }
+        }
+        Ok(Self {
+            inner,
+            boundaries,
+            noise,
+            integer_step_probability,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns the wrapped agent.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Returns the wrapped agent mutably.
+    pub fn inner_mut(&mut self) -> &mut A {
+        &mut self.inner
+    }
+
+    /// Consumes the wrapper and returns the wrapped agent.
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+impl<A, E, R, S> Agent<WrappedAgentError<E>, R, GaussianActionNoiseStorage<S>>
+    for GaussianActionNoise<A, E, R, S>
+where
+    A: Agent<E, R, S>,
+    E: std::error::Error,
+    R: Reward,
+    S: Serialize + DeserializeOwned,
+{
+    /// Reseeds this wrapper and the inner agent with a seed drawn from the wrapper.
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), WrappedAgentError<E>> {
+        self.rng.reseed(random_seed);
+        let inner_seed = self.rng.derive_seed();
+        self.inner
+            .reseed(Some(inner_seed))
+            .map_err(WrappedAgentError::Inner)
+    }
+
+    fn reset(&mut self) -> Result<(), WrappedAgentError<E>> {
+        self.inner.reset().map_err(WrappedAgentError::Inner)
+    }
+
+    fn choose_action(
+        &mut self,
+        state: &EnvironmentState,
+    ) -> Result<AgentAction, WrappedAgentError<E>> {
+        let inner_action = self
+            .inner
+            .choose_action(state)
+            .map_err(WrappedAgentError::Inner)?;
+        let mut values = util::flatten_values(inner_action.dimensions(), &inner_action);
+        for ((value, boundaries), noise) in values
+            .iter_mut()
+            .zip(self.boundaries.iter())
+            .zip(self.noise.iter())
+        {
+            match *value {
+                DimensionValue::Float(float) => {
+                    *value = util::clamp_into(boundaries, float + noise.sample(&mut self.rng.rng));
+                }
+                DimensionValue::Integer(integer) => {
+                    if let Some(probability) = self.integer_step_probability {
+                        if self.rng.rng.gen_bool(probability) {
+                            let step = if self.rng.rng.gen_bool(0.5) { 1 } else { -1 };
+                            *value =
+                                util::clamp_into(boundaries, integer.saturating_add(step) as f64);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(util::compose_action(inner_action.dimensions(), values))
+    }
+
+    fn process_reward(
+        &mut self,
+        old_state: &EnvironmentState,
+        last_action: &AgentAction,
+        new_state: &EnvironmentState,
+        reward: R,
+        is_done: bool,
+    ) -> Result<(), WrappedAgentError<E>> {
+        self.inner
+            .process_reward(old_state, last_action, new_state, reward, is_done)
+            .map_err(WrappedAgentError::Inner)
+    }
+
+    fn load(&mut self, data: GaussianActionNoiseStorage<S>) -> Result<(), WrappedAgentError<E>> {
+        self.inner
+            .load(data.inner)
+            .map_err(WrappedAgentError::Inner)?;
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        Ok(())
+    }
+
+    fn store(&self) -> GaussianActionNoiseStorage<S> {
+        GaussianActionNoiseStorage {
+            inner: self.inner.store(),
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), WrappedAgentError<E>> {
+        self.inner.close().map_err(WrappedAgentError::Inner)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GaussianActionNoiseStorage<S> {
+    inner: S,
+    last_seed: Seed,
+    rng_word_pos: u128,
+}
@@ -0,0 +1,179 @@
+//! Agent serving a Latin hypercube design over the action space.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::DimensionBoundaries;
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Agent which chooses his actions from a Latin hypercube design with a fixed budget of actions.
+///
+/// Every dimension of the ActionSpace is divided into `budget` equally sized strata and each
+/// stratum is used by exactly one of the `budget` actions, so every dimension on its own is
+/// stratified across the whole budget. The design is drawn from the seed and served in order,
+/// across episodes. After `budget` actions every further `choose_action` fails with
+/// `RandomAgentError::BudgetExhausted` until the agent gets reseeded.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::LatinHypercubeAgent;
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: LatinHypercubeAgent<f64> = LatinHypercubeAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     2,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// assert!(agent.choose_action(&EnvironmentState::default()).is_ok());
+/// assert!(agent.choose_action(&EnvironmentState::default()).is_ok());
+/// assert!(agent.choose_action(&EnvironmentState::default()).is_err());
+/// ```
+pub struct LatinHypercubeAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    budget: usize,
+    design: Vec<Vec<f64>>,
+    next_index: usize,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> LatinHypercubeAgent<R> {
+    /// Creates a new LatinHypercubeAgent with the provided ActionSpace serving `budget` actions.
+    ///
+    /// Fails if any float boundaries are infinite.
+    pub fn with(action_spaces: ActionSpace, budget: usize) -> Result<Self, RandomAgentError> {
+        if budget == 0 {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: "budget has to be at least 1".to_string(),
+            });
+        }
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        util::require_finite_boundaries(&boundaries, "latin hypercube design")?;
+        let mut agent = Self {
+            boundaries,
+            action_spaces,
+            budget,
+            design: Vec::new(),
+            next_index: 0,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        };
+        agent.generate_design();
+        Ok(agent)
+    }
+
+    /// Returns the total number of actions within the design.
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Returns the number of actions which can still be chosen.
+    pub fn remaining(&self) -> usize {
+        self.budget - self.next_index
+    }
+
+    /// Draws the design as one column of unit values per dimension.
+    fn generate_design(&mut self) {
+        let mut design = Vec::with_capacity(self.boundaries.len());
+        for _ in 0..self.boundaries.len() {
+            let mut strata: Vec<usize> = (0..self.budget).collect();
+            strata.shuffle(&mut self.rng.rng);
+            let budget = self.budget as f64;
+            let rng = &mut self.rng.rng;
+            design.push(
+                strata
+                    .into_iter()
+                    .map(|stratum| (stratum as f64 + rng.gen::<f64>()) / budget)
+                    .collect(),
+            );
+        }
+        self.design = design;
+        self.next_index = 0;
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, LatinHypercubeAgentStorage> for LatinHypercubeAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        self.generate_design();
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        if self.next_index >= self.budget {
+            return Err(RandomAgentError::BudgetExhausted {
+                budget: self.budget as u64,
+            });
+        }
+        let values = self
+            .boundaries
+            .iter()
+            .zip(self.design.iter())
+            .map(|(boundaries, column)| util::map_unit_into(boundaries, column[self.next_index]))
+            .collect();
+        self.next_index += 1;
+        Ok(util::compose_action(
+            self.action_spaces.dimensions(),
+            values,
+        ))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: LatinHypercubeAgentStorage) -> Result<(), RandomAgentError> {
+        if data.next_index > self.budget {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "stored design has served {} actions, but its budget is only {}",
+                    data.next_index, self.budget
+                ),
+            });
+        }
+        self.rng.restore(data.last_seed, 0);
+        self.generate_design();
+        self.next_index = data.next_index;
+        Ok(())
+    }
+
+    fn store(&self) -> LatinHypercubeAgentStorage {
+        LatinHypercubeAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            next_index: self.next_index,
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct LatinHypercubeAgentStorage {
+    last_seed: Seed,
+    next_index: usize,
+}
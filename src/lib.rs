@@ -6,11 +6,77 @@
 extern crate gymnarium_base;
 extern crate rand;
 extern crate rand_chacha;
+extern crate rand_distr;
 extern crate serde;
 
+mod action_repeat;
+mod adversarial;
+mod augmented_random_search;
+mod colored_noise;
+mod constrained;
+mod cross_entropy;
+mod deck;
+mod dirichlet;
+mod distribution;
+mod enumeration;
+mod epsilon;
+mod fuzz;
+mod gaussian_noise;
+mod latin_hypercube;
+mod linear;
+mod masked;
+mod mlp;
+mod noop_starts;
+mod ornstein_uhlenbeck;
+mod policy;
+mod quasi_random;
+mod random_search;
+mod state_hashed;
+mod sticky;
+mod util;
+
+pub use action_repeat::{ActionRepeatAgent, ActionRepeatAgentStorage, RepeatCount};
+pub use adversarial::{
+    AdversarialAgent, AdversarialAgentStorage, InvalidActionKind, InvalidActionProbabilities,
+};
+pub use augmented_random_search::{
+    AugmentedRandomSearchAgent, AugmentedRandomSearchAgentStorage, AugmentedRandomSearchParameters,
+};
+pub use colored_noise::{ColoredNoiseAgent, ColoredNoiseAgentStorage};
+pub use constrained::{
+    AcceptanceStatistics, ActionConstraint, ConstrainedRandomAgent, ConstrainedRandomAgentStorage,
+};
+pub use cross_entropy::{
+    CrossEntropyAgent, CrossEntropyAgentStorage, CrossEntropyDistribution, CrossEntropyParameters,
+    MAXIMUM_CATEGORICAL_VALUES,
+};
+pub use deck::{DeckRandomAgent, DeckRandomAgentStorage, DeckRefill};
+pub use dirichlet::{DirichletAgent, DirichletAgentStorage};
+pub use distribution::{DimensionDistribution, OutOfBoundsHandling};
+pub use enumeration::{
+    EnumerationAgent, EnumerationAgentStorage, EnumerationOrder, MAXIMUM_ENUMERATED_ACTIONS,
+};
+pub use epsilon::{EpsilonRandom, EpsilonRandomStorage, EpsilonSchedule};
+pub use fuzz::{FuzzAgent, FuzzAgentStorage, FuzzCase, FuzzProbabilities};
+pub use gaussian_noise::{GaussianActionNoise, GaussianActionNoiseStorage};
+pub use latin_hypercube::{LatinHypercubeAgent, LatinHypercubeAgentStorage};
+pub use linear::{RandomLinearPolicyAgent, RandomLinearPolicyAgentStorage};
+pub use masked::{ActionMask, MaskedRandomAgent, MaskedRandomAgentStorage};
+pub use mlp::{Activation, RandomMlpAgent, RandomMlpAgentStorage, WeightInitialization};
+pub use noop_starts::{NoopStartsAgent, NoopStartsAgentStorage};
+pub use ornstein_uhlenbeck::{
+    OrnsteinUhlenbeckAgent, OrnsteinUhlenbeckAgentStorage, OrnsteinUhlenbeckParameters,
+};
+pub use policy::{LinearPolicyParameters, MAXIMUM_ARGMAX_CHOICES};
+pub use quasi_random::{QuasiRandomAgent, QuasiRandomAgentStorage};
+pub use random_search::{RandomSearchAgent, RandomSearchAgentStorage, SearchMode};
+pub use state_hashed::{StateHashedRandomAgent, StateHashedRandomAgentStorage};
+pub use sticky::{StickyRandomAgent, StickyRandomAgentStorage};
+
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use gymnarium_base::space::DimensionBoundaries;
 use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
 
 use rand::SeedableRng;
@@ -20,19 +86,136 @@ use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Serialize};
 
 /// Possible errors occurring within this library.
-///
-/// Currently there are none and I don't think some will be here in the future.
 #[derive(Debug)]
-pub enum RandomAgentError {}
+pub enum RandomAgentError {
+    /// The number of provided distributions differs from the number of dimensions.
+    DistributionCountMismatch { expected: usize, actual: usize },
+    /// The distribution cannot be used with the boundaries of its dimension.
+    IncompatibleDistribution { dimension: usize, reason: String },
+    /// A parameter of the distribution is out of its valid range.
+    InvalidDistributionParameter { dimension: usize, reason: String },
+    /// The number of categorical weights differs from the number of integers within the
+    /// boundaries of its dimension.
+    WeightCountMismatch {
+        dimension: usize,
+        expected: u128,
+        actual: usize,
+    },
+    /// The number of provided parameter sets differs from the number of dimensions.
+    ParameterCountMismatch { expected: usize, actual: usize },
+    /// The configuration of the agent is invalid.
+    InvalidConfiguration { reason: String },
+    /// All actions of the limited budget have already been chosen.
+    BudgetExhausted { budget: u64 },
+    /// The dimension has float boundaries, so its values cannot be enumerated.
+    NotEnumerable { dimension: usize },
+    /// The ActionSpace contains more actions than can be enumerated.
+    TooManyActions { maximum: u64 },
+    /// The ActionMask allows no value within the boundaries of the dimension.
+    NoLegalAction { dimension: usize },
+    /// None of the sampled actions satisfied the constraint.
+    MaximumAttemptsExceeded { attempts: u64 },
+    /// The state doesn't have as many values as the policy has inputs.
+    StateSizeMismatch { expected: usize, actual: usize },
+}
 
 impl std::fmt::Display for RandomAgentError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "")
+        match self {
+            Self::DistributionCountMismatch { expected, actual } => write!(
+                f,
+                "Expected {} distributions (one per dimension), but got {}.",
+                expected, actual
+            ),
+            Self::IncompatibleDistribution { dimension, reason } => write!(
+                f,
+                "Distribution for dimension {} is incompatible: {}.",
+                dimension, reason
+            ),
+            Self::InvalidDistributionParameter { dimension, reason } => write!(
+                f,
+                "Distribution for dimension {} is invalid: {}.",
+                dimension, reason
+            ),
+            Self::WeightCountMismatch {
+                dimension,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Expected {} weights for dimension {} (one per integer within the boundaries), but got {}.",
+                expected, dimension, actual
+            ),
+            Self::ParameterCountMismatch { expected, actual } => write!(
+                f,
+                "Expected {} parameter sets (one per dimension), but got {}.",
+                expected, actual
+            ),
+            Self::InvalidConfiguration { reason } => {
+                write!(f, "Invalid configuration: {}.", reason)
+            }
+            Self::BudgetExhausted { budget } => write!(
+                f,
+                "The budget of {} actions is exhausted, reseed the agent to start anew.",
+                budget
+            ),
+            Self::NotEnumerable { dimension } => write!(
+                f,
+                "Dimension {} has float boundaries and cannot be enumerated.",
+                dimension
+            ),
+            Self::TooManyActions { maximum } => write!(
+                f,
+                "The action space contains more than {} actions and is too large to enumerate.",
+                maximum
+            ),
+            Self::NoLegalAction { dimension } => write!(
+                f,
+                "No legal value remains for dimension {} after applying the action mask.",
+                dimension
+            ),
+            Self::MaximumAttemptsExceeded { attempts } => write!(
+                f,
+                "None of {} sampled actions satisfied the constraint.",
+                attempts
+            ),
+            Self::StateSizeMismatch { expected, actual } => write!(
+                f,
+                "Expected a state with {} values (one per policy input), but got {}.",
+                expected, actual
+            ),
+        }
     }
 }
 
 impl std::error::Error for RandomAgentError {}
 
+/// Possible errors occurring within agents wrapping another agent.
+#[derive(Debug)]
+pub enum WrappedAgentError<E: std::error::Error> {
+    /// The wrapped agent returned an error.
+    Inner(E),
+    /// The random part of the wrapping agent returned an error.
+    Random(RandomAgentError),
+}
+
+impl<E: std::error::Error> std::fmt::Display for WrappedAgentError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Inner(error) => write!(f, "Wrapped agent failed: {}", error),
+            Self::Random(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for WrappedAgentError<E> {}
+
+impl<E: std::error::Error> From<RandomAgentError> for WrappedAgentError<E> {
+    fn from(error: RandomAgentError) -> Self {
+        Self::Random(error)
+    }
+}
+
 /// Agent which chooses his actions through random number generation.
 ///
 /// # Example
@@ -55,8 +238,43 @@ impl std::error::Error for RandomAgentError {}
 /// assert_eq!(DimensionValue::Integer(2), chosen_action[&[0]]);
 /// assert_eq!(DimensionValue::Float(2.0), chosen_action[&[1]]);
 /// ```
+///
+/// Instead of uniformly each dimension can be sampled from its own distribution:
+///
+/// ```
+/// use gymnarium_agents_random::{DimensionDistribution, OutOfBoundsHandling, RandomAgent};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut random_agent: RandomAgent<f64> = RandomAgent::with_distributions(
+///     ActionSpace::simple(vec![
+///         DimensionBoundaries::from(1..=2),
+///         DimensionBoundaries::from(-1.0..=1.0)
+///     ]),
+///     vec![
+///         DimensionDistribution::Uniform,
+///         DimensionDistribution::Gaussian {
+///             mean: 0.0,
+///             standard_deviation: 0.2,
+///             out_of_bounds: OutOfBoundsHandling::Clip,
+///         },
+///     ],
+/// ).unwrap();
+/// random_agent.reseed(Some(Seed::from(0))).unwrap();
+/// random_agent.reset().unwrap();
+///
+/// let chosen_action = random_agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// if let DimensionValue::Float(value) = chosen_action[&[1]] {
+///     assert!((-1.0..=1.0).contains(&value));
+/// } else {
+///     panic!("expected a float");
+/// }
+/// ```
 pub struct RandomAgent<R: Reward> {
     action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    distributions: Option<Vec<DimensionDistribution>>,
     last_seed: Seed,
     rng: ChaCha20Rng,
     _phantom_data: PhantomData<R>,
@@ -68,11 +286,39 @@ impl<R: Reward> RandomAgent<R> {
         let last_seed = Seed::new_random();
         Self {
             action_spaces,
+            boundaries: Vec::new(),
+            distributions: None,
             last_seed: last_seed.clone(),
             rng: ChaCha20Rng::from_seed(last_seed.into()),
             _phantom_data: PhantomData::default(),
         }
     }
+
+    /// Creates a new RandomAgent sampling every dimension of the ActionSpace from its own
+    /// distribution.
+    ///
+    /// The distributions are given in row-major order of the dimensions.
+    pub fn with_distributions(
+        action_spaces: ActionSpace,
+        distributions: Vec<DimensionDistribution>,
+    ) -> Result<Self, RandomAgentError> {
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        if boundaries.len() != distributions.len() {
+            return Err(RandomAgentError::DistributionCountMismatch {
+                expected: boundaries.len(),
+                actual: distributions.len(),
+            });
+        }
+        for (dimension, (distribution, boundaries)) in
+            distributions.iter().zip(boundaries.iter()).enumerate()
+        {
+            distribution.validate(dimension, boundaries)?;
+        }
+        let mut agent = Self::with(action_spaces);
+        agent.boundaries = boundaries;
+        agent.distributions = Some(distributions);
+        Ok(agent)
+    }
 }
 
 impl<R: Reward> Agent<RandomAgentError, R, RandomAgentStorage> for RandomAgent<R> {
@@ -92,7 +338,21 @@ impl<R: Reward> Agent<RandomAgentError, R, RandomAgentStorage> for RandomAgent<R
     }
 
     fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
-        Ok(self.action_spaces.sample_with(&mut self.rng))
+        if let Some(distributions) = &self.distributions {
+            let rng = &mut self.rng;
+            let values = self
+                .boundaries
+                .iter()
+                .zip(distributions.iter())
+                .map(|(boundaries, distribution)| distribution.sample(boundaries, rng))
+                .collect();
+            Ok(util::compose_action(
+                self.action_spaces.dimensions(),
+                values,
+            ))
+        } else {
+            Ok(self.action_spaces.sample_with(&mut self.rng))
+        }
     }
 
     fn process_reward(
@@ -0,0 +1,161 @@
+//! Agent following a linear policy with random weights.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use serde::{Deserialize, Serialize};
+
+use crate::policy::{self, LinearPolicyParameters, OutputMapping};
+use crate::util::SeededRng;
+use crate::RandomAgentError;
+
+/// Agent which chooses its actions with the linear policy `W·state + b`, whose weights and
+/// biases are drawn from a normal distribution on every reset.
+///
+/// The state has to consist of `input_size` values, which are read in row-major order. The
+/// outputs are squashed into the ActionSpace: float dimensions are scaled with `tanh` and integer
+/// dimensions use an argmax over one output per value (see `MAXIMUM_ARGMAX_CHOICES`).
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::RandomLinearPolicyAgent;
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut agent: RandomLinearPolicyAgent<f64> = RandomLinearPolicyAgent::with(
+///     ActionSpace::simple(vec![
+///         DimensionBoundaries::from(-1.0..=1.0),
+///         DimensionBoundaries::from(0..=3),
+///     ]),
+///     2,
+///     1.0,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let state = EnvironmentState::simple(vec![
+///     DimensionValue::Float(0.5),
+///     DimensionValue::Float(-0.25),
+/// ]);
+/// let first_action = agent.choose_action(&state).unwrap();
+/// let second_action = agent.choose_action(&state).unwrap();
+///
+/// assert_eq!(first_action, second_action);
+/// ```
+pub struct RandomLinearPolicyAgent<R: Reward> {
+    mapping: OutputMapping,
+    input_size: usize,
+    standard_deviation: f64,
+    parameters: LinearPolicyParameters,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> RandomLinearPolicyAgent<R> {
+    /// Creates a new RandomLinearPolicyAgent with the provided ActionSpace for states consisting
+    /// of `input_size` values, drawing its parameters with the given standard deviation.
+    pub fn with(
+        action_spaces: ActionSpace,
+        input_size: usize,
+        standard_deviation: f64,
+    ) -> Result<Self, RandomAgentError> {
+        if !standard_deviation.is_finite() || standard_deviation < 0.0 {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "standard deviation has to be finite and not negative, but is {}",
+                    standard_deviation
+                ),
+            });
+        }
+        let mapping = OutputMapping::new(&action_spaces);
+        let mut rng = SeededRng::new_random();
+        let parameters = LinearPolicyParameters::sample(
+            input_size,
+            mapping.output_size(),
+            standard_deviation,
+            &mut rng.rng,
+        );
+        Ok(Self {
+            mapping,
+            input_size,
+            standard_deviation,
+            parameters,
+            rng,
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns the current weights and biases.
+    pub fn parameters(&self) -> &LinearPolicyParameters {
+        &self.parameters
+    }
+
+    /// Returns the number of outputs of the policy.
+    pub fn output_size(&self) -> usize {
+        self.mapping.output_size()
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, RandomLinearPolicyAgentStorage>
+    for RandomLinearPolicyAgent<R>
+{
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        self.parameters = LinearPolicyParameters::sample(
+            self.input_size,
+            self.mapping.output_size(),
+            self.standard_deviation,
+            &mut self.rng.rng,
+        );
+        Ok(())
+    }
+
+    fn choose_action(&mut self, state: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let features = policy::state_features(state, self.input_size)?;
+        Ok(self.mapping.action(&self.parameters.evaluate(&features)))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: RandomLinearPolicyAgentStorage) -> Result<(), RandomAgentError> {
+        data.parameters
+            .check_shape(self.input_size, self.mapping.output_size())?;
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.parameters = data.parameters;
+        Ok(())
+    }
+
+    fn store(&self) -> RandomLinearPolicyAgentStorage {
+        RandomLinearPolicyAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            parameters: self.parameters.clone(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RandomLinearPolicyAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    parameters: LinearPolicyParameters,
+}
@@ -0,0 +1,160 @@
+//! Agent sampling only legal actions according to a mask derived from the environment state.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::seq::SliceRandom;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Extracts the legal actions out of an EnvironmentState.
+///
+/// It is implemented for all closures `Fn(&EnvironmentState) -> Vec<Option<Vec<i64>>>`.
+pub trait ActionMask {
+    /// Returns one entry per dimension of the ActionSpace in row-major order.
+    ///
+    /// `None` allows every value within the boundaries, while `Some` contains the allowed values.
+    /// Values outside of the boundaries are ignored, as are entries of float dimensions, and
+    /// duplicate values are counted once.
+    fn allowed_values(&self, state: &EnvironmentState) -> Vec<Option<Vec<i64>>>;
+}
+
+impl<F> ActionMask for F
+where
+    F: Fn(&EnvironmentState) -> Vec<Option<Vec<i64>>>,
+{
+    fn allowed_values(&self, state: &EnvironmentState) -> Vec<Option<Vec<i64>>> {
+        self(state)
+    }
+}
+
+/// Agent which chooses his actions uniformly among the legal ones given by an ActionMask.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::MaskedRandomAgent;
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut agent: MaskedRandomAgent<f64, _> = MaskedRandomAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(0..=5)]),
+///     |_: &EnvironmentState| vec![Some(vec![2, 4])],
+/// );
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let chosen_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert!(chosen_action[&[0]] == DimensionValue::Integer(2)
+///     || chosen_action[&[0]] == DimensionValue::Integer(4));
+/// ```
+pub struct MaskedRandomAgent<R: Reward, M: ActionMask> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    mask: M,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward, M: ActionMask> MaskedRandomAgent<R, M> {
+    /// Creates a new MaskedRandomAgent with the provided ActionSpace and ActionMask.
+    pub fn with(action_spaces: ActionSpace, mask: M) -> Self {
+        Self {
+            boundaries: util::flatten_boundaries(&action_spaces),
+            action_spaces,
+            mask,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        }
+    }
+}
+
+impl<R: Reward, M: ActionMask> Agent<RandomAgentError, R, MaskedRandomAgentStorage>
+    for MaskedRandomAgent<R, M>
+{
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn choose_action(&mut self, state: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let allowed_values = self.mask.allowed_values(state);
+        if allowed_values.len() != self.boundaries.len() {
+            return Err(RandomAgentError::ParameterCountMismatch {
+                expected: self.boundaries.len(),
+                actual: allowed_values.len(),
+            });
+        }
+        let mut values = Vec::with_capacity(self.boundaries.len());
+        for (dimension, (boundaries, allowed)) in self
+            .boundaries
+            .iter()
+            .zip(allowed_values.into_iter())
+            .enumerate()
+        {
+            values.push(match (boundaries, allowed) {
+                (DimensionBoundaries::Integer { minimum, maximum }, Some(allowed)) => {
+                    let mut legal: Vec<i64> = allowed
+                        .into_iter()
+                        .filter(|value| (minimum..=maximum).contains(&value))
+                        .collect();
+                    legal.sort_unstable();
+                    legal.dedup();
+                    DimensionValue::Integer(
+                        *legal
+                            .choose(&mut self.rng.rng)
+                            .ok_or(RandomAgentError::NoLegalAction { dimension })?,
+                    )
+                }
+                _ => util::sample_uniform(boundaries, &mut self.rng.rng),
+            });
+        }
+        Ok(util::compose_action(
+            self.action_spaces.dimensions(),
+            values,
+        ))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: MaskedRandomAgentStorage) -> Result<(), RandomAgentError> {
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        Ok(())
+    }
+
+    fn store(&self) -> MaskedRandomAgentStorage {
+        MaskedRandomAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MaskedRandomAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+}
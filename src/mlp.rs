@@ -0,0 +1,258 @@
+//! Agent following a multilayer perceptron with random weights.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use serde::{Deserialize, Serialize};
+
+use crate::policy::{self, LinearPolicyParameters, OutputMapping};
+use crate::util::SeededRng;
+use crate::RandomAgentError;
+
+/// Activation function applied after every hidden layer of a RandomMlpAgent.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Activation {
+    Identity,
+    Tanh,
+    Relu,
+    Sigmoid,
+}
+
+impl Activation {
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Identity => value,
+            Self::Tanh => value.tanh(),
+            Self::Relu => value.max(0.0),
+            Self::Sigmoid => 1.0 / (1.0 + (-value).exp()),
+        }
+    }
+}
+
+/// Scheme for drawing the weights of a RandomMlpAgent.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WeightInitialization {
+    /// Normal distribution with standard deviation `sqrt(2 / (inputs + outputs))` per layer and
+    /// biases of zero (Glorot & Bengio, 2010).
+    Xavier,
+    /// Normal distribution with standard deviation `sqrt(2 / inputs)` per layer and biases of zero
+    /// (He et al., 2015).
+    He,
+    /// Normal distribution with a fixed standard deviation for all weights and biases.
+    Normal { standard_deviation: f64 },
+}
+
+impl WeightInitialization {
+    fn sample_layer(
+        self,
+        input_size: usize,
+        output_size: usize,
+        rng: &mut SeededRng,
+    ) -> LinearPolicyParameters {
+        let standard_deviation = match self {
+            Self::Xavier => (2.0 / (input_size + output_size).max(1) as f64).sqrt(),
+            Self::He => (2.0 / input_size.max(1) as f64).sqrt(),
+            Self::Normal { standard_deviation } => standard_deviation,
+        };
+        let mut layer = LinearPolicyParameters::sample(
+            input_size,
+            output_size,
+            standard_deviation,
+            &mut rng.rng,
+        );
+        if !matches!(self, Self::Normal { .. }) {
+            layer.biases = vec![0.0; output_size];
+        }
+        layer
+    }
+}
+
+/// Agent which chooses its actions with a fully connected neural network whose weights are drawn
+/// on every reset.
+///
+/// The state has to consist of `input_size` values, which are read in row-major order. The
+/// activation is applied after every hidden layer, while the outputs of the last layer are
+/// squashed into the ActionSpace like `RandomLinearPolicyAgent` does.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{Activation, RandomMlpAgent, WeightInitialization};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut agent: RandomMlpAgent<f64> = RandomMlpAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     2,
+///     vec![16, 16],
+///     Activation::Tanh,
+///     WeightInitialization::Xavier,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let state = EnvironmentState::simple(vec![
+///     DimensionValue::Float(0.5),
+///     DimensionValue::Float(-0.25),
+/// ]);
+/// agent.choose_action(&state).unwrap();
+///
+/// assert_eq!(3, agent.layers().len());
+/// ```
+pub struct RandomMlpAgent<R: Reward> {
+    mapping: OutputMapping,
+    input_size: usize,
+    hidden_sizes: Vec<usize>,
+    activation: Activation,
+    initialization: WeightInitialization,
+    layers: Vec<LinearPolicyParameters>,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> RandomMlpAgent<R> {
+    /// Creates a new RandomMlpAgent with the provided ActionSpace for states consisting of
+    /// `input_size` values and hidden layers of the given sizes.
+    pub fn with(
+        action_spaces: ActionSpace,
+        input_size: usize,
+        hidden_sizes: Vec<usize>,
+        activation: Activation,
+        initialization: WeightInitialization,
+    ) -> Result<Self, RandomAgentError> {
+        if hidden_sizes.contains(&0) {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "hidden layers have to contain at least one neuron, but are {:?}",
+                    hidden_sizes
+                ),
+            });
+        }
+        if let WeightInitialization::Normal { standard_deviation } = initialization {
+            if !standard_deviation.is_finite() || standard_deviation < 0.0 {
+                return Err(RandomAgentError::InvalidConfiguration {
+                    reason: format!(
+                        "standard deviation has to be finite and not negative, but is {}",
+                        standard_deviation
+                    ),
+                });
+            }
+        }
+        let mut agent = Self {
+            mapping: OutputMapping::new(&action_spaces),
+            input_size,
+            hidden_sizes,
+            activation,
+            initialization,
+            layers: Vec::new(),
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        };
+        agent.layers = agent.sample_layers();
+        Ok(agent)
+    }
+
+    /// Returns the weights and biases of every layer, including the output layer.
+    pub fn layers(&self) -> &[LinearPolicyParameters] {
+        &self.layers
+    }
+
+    /// Returns the number of outputs of the network.
+    pub fn output_size(&self) -> usize {
+        self.mapping.output_size()
+    }
+
+    fn layer_sizes(&self) -> Vec<usize> {
+        let mut sizes = Vec::with_capacity(self.hidden_sizes.len() + 2);
+        sizes.push(self.input_size);
+        sizes.extend(self.hidden_sizes.iter());
+        sizes.push(self.mapping.output_size());
+        sizes
+    }
+
+    fn sample_layers(&mut self) -> Vec<LinearPolicyParameters> {
+        let sizes = self.layer_sizes();
+        let initialization = self.initialization;
+        let rng = &mut self.rng;
+        sizes
+            .windows(2)
+            .map(|sizes| initialization.sample_layer(sizes[0], sizes[1], rng))
+            .collect()
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, RandomMlpAgentStorage> for RandomMlpAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        self.layers = self.sample_layers();
+        Ok(())
+    }
+
+    fn choose_action(&mut self, state: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let mut values = policy::state_features(state, self.input_size)?;
+        let hidden_layers = self.layers.len() - 1;
+        for (index, layer) in self.layers.iter().enumerate() {
+            values = layer.evaluate(&values);
+            if index < hidden_layers {
+                for value in values.iter_mut() {
+                    *value = self.activation.apply(*value);
+                }
+            }
+        }
+        Ok(self.mapping.action(&values))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: RandomMlpAgentStorage) -> Result<(), RandomAgentError> {
+        let sizes = self.layer_sizes();
+        if data.layers.len() != sizes.len() - 1 {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "stored network has {} layers, but {} are expected",
+                    data.layers.len(),
+                    sizes.len() - 1
+                ),
+            });
+        }
+        for (layer, sizes) in data.layers.iter().zip(sizes.windows(2)) {
+            layer.check_shape(sizes[0], sizes[1])?;
+        }
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.layers = data.layers;
+        Ok(())
+    }
+
+    fn store(&self) -> RandomMlpAgentStorage {
+        RandomMlpAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            layers: self.layers.clone(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RandomMlpAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    layers: Vec<LinearPolicyParameters>,
+}
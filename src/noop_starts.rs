@@ -0,0 +1,174 @@
+//! Wrapper starting every episode with a random number of no-op actions.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::{Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::distributions::{Distribution, Uniform};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::util::SeededRng;
+use crate::WrappedAgentError;
+
+/// Agent wrapping another agent, which emits a fixed no-op action for a random number of steps at
+/// the start of every episode before handing control to the inner agent.
+///
+/// The number of no-op steps is drawn uniformly from `0..=maximum_noops` on every `Agent::reset`.
+/// Rewards of all steps, including the no-op ones, are forwarded to the inner agent.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{NoopStartsAgent, RandomAgent};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let action_space = ActionSpace::simple(vec![DimensionBoundaries::from(0..=0)]);
+/// let noop_action = RandomAgent::<f64>::with(action_space.clone())
+///     .choose_action(&EnvironmentState::default())
+///     .unwrap();
+/// let inner_agent: RandomAgent<f64> = RandomAgent::with(action_space);
+/// let mut agent = NoopStartsAgent::with(inner_agent, noop_action, 30);
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// assert!(agent.remaining_noops() <= 30);
+/// ```
+pub struct NoopStartsAgent<A, E, R, S>
+where
+    A: Agent<E, R, S>,
+    E: std::error::Error,
+    R: Reward,
+    S: Serialize + DeserializeOwned,
+{
+    inner: A,
+    noop_action: AgentAction,
+    maximum_noops: usize,
+    remaining_noops: usize,
+    rng: SeededRng,
+    _phantom_data: PhantomData<(E, R, S)>,
+}
+
+impl<A, E, R, S> NoopStartsAgent<A, E, R, S>
+where
+    A: Agent<E, R, S>,
+    E: std::error::Error,
+    R: Reward,
+    S: Serialize + DeserializeOwned,
+{
+    /// Creates a new NoopStartsAgent around the inner agent emitting `noop_action` for up to
+    /// `maximum_noops` steps at the start of every episode.
+    pub fn with(inner: A, noop_action: AgentAction, maximum_noops: usize) -> Self {
+        Self {
+            inner,
+            noop_action,
+            maximum_noops,
+            remaining_noops: 0,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        }
+    }
+
+    /// Returns the number of no-op actions left in the current episode.
+    pub fn remaining_noops(&self) -> usize {
+        self.remaining_noops
+    }
+
+    /// Returns the wrapped agent.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Returns the wrapped agent mutably.
+    pub fn inner_mut(&mut self) -> &mut A {
+        &mut self.inner
+    }
+
+    /// Consumes the wrapper and returns the wrapped agent.
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+impl<A, E, R, S> Agent<WrappedAgentError<E>, R, NoopStartsAgentStorage<S>>
+    for NoopStartsAgent<A, E, R, S>
+where
+    A: Agent<E, R, S>,
+    E: std::error::Error,
+    R: Reward,
+    S: Serialize + DeserializeOwned,
+{
+    /// Reseeds this wrapper and the inner agent with a seed drawn from the wrapper.
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), WrappedAgentError<E>> {
+        self.rng.reseed(random_seed);
+        let inner_seed = self.rng.derive_seed();
+        self.inner
+            .reseed(Some(inner_seed))
+            .map_err(WrappedAgentError::Inner)
+    }
+
+    fn reset(&mut self) -> Result<(), WrappedAgentError<E>> {
+        self.remaining_noops =
+            Uniform::new_inclusive(0, self.maximum_noops).sample(&mut self.rng.rng);
+        self.inner.reset().map_err(WrappedAgentError::Inner)
+    }
+
+    fn choose_action(
+        &mut self,
+        state: &EnvironmentState,
+    ) -> Result<AgentAction, WrappedAgentError<E>> {
+        if self.remaining_noops > 0 {
+            self.remaining_noops -= 1;
+            Ok(self.noop_action.clone())
+        } else {
+            self.inner
+                .choose_action(state)
+                .map_err(WrappedAgentError::Inner)
+        }
+    }
+
+    fn process_reward(
+        &mut self,
+        old_state: &EnvironmentState,
+        last_action: &AgentAction,
+        new_state: &EnvironmentState,
+        reward: R,
+        is_done: bool,
+    ) -> Result<(), WrappedAgentError<E>> {
+        self.inner
+            .process_reward(old_state, last_action, new_state, reward, is_done)
+            .map_err(WrappedAgentError::Inner)
+    }
+
+    fn load(&mut self, data: NoopStartsAgentStorage<S>) -> Result<(), WrappedAgentError<E>> {
+        self.inner
+            .load(data.inner)
+            .map_err(WrappedAgentError::Inner)?;
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.remaining_noops = data.remaining_noops;
+        Ok(())
+    }
+
+    fn store(&self) -> NoopStartsAgentStorage<S> {
+        NoopStartsAgentStorage {
+            inner: self.inner.store(),
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            remaining_noops: self.remaining_noops,
+        }
+    }
+
+    fn close(&mut self) -> Result<(), WrappedAgentError<E>> {
+        self.inner.close().map_err(WrappedAgentError::Inner)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct NoopStartsAgentStorage<S> {
+    inner: S,
+    last_seed: Seed,
+    rng_word_pos: u128,
+    remaining_noops: usize,
+}
@@ -0,0 +1,236 @@
+//! Agent producing temporally correlated actions through an Ornstein-Uhlenbeck process.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::DimensionBoundaries;
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand_distr::{Distribution, StandardNormal};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Parameters of the Ornstein-Uhlenbeck process of a single dimension.
+///
+/// Every step the noise `x` is updated through
+/// `x += theta * (mu - x) * dt + sigma * sqrt(dt) * N(0, 1)`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrnsteinUhlenbeckParameters {
+    /// Rate with which the noise is pulled back to `mu`.
+    pub theta: f64,
+    /// Scale of the random perturbation.
+    pub sigma: f64,
+    /// Long-term mean and starting value of the noise.
+    pub mu: f64,
+    /// Time step between two actions.
+    pub dt: f64,
+}
+
+impl Default for OrnsteinUhlenbeckParameters {
+    fn default() -> Self {
+        Self {
+            theta: 0.15,
+            sigma: 0.2,
+            mu: 0.0,
+            dt: 1e-2,
+        }
+    }
+}
+
+/// Agent which chooses his actions by following an Ornstein-Uhlenbeck process per dimension.
+///
+/// The noise is clipped into the boundaries of its dimension and rounded for integer dimensions.
+/// It is reset to `mu` on `Agent::reset`.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{OrnsteinUhlenbeckAgent, OrnsteinUhlenbeckParameters};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: OrnsteinUhlenbeckAgent<f64> = OrnsteinUhlenbeckAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     vec![OrnsteinUhlenbeckParameters::default()],
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let chosen_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_eq!(&vec![1], chosen_action.dimensions());
+/// ```
+///
+/// Storing and loading resumes the exact trajectory:
+///
+/// ```
+/// use gymnarium_agents_random::{OrnsteinUhlenbeckAgent, OrnsteinUhlenbeckParameters};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: OrnsteinUhlenbeckAgent<f64> = OrnsteinUhlenbeckAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     vec![OrnsteinUhlenbeckParameters::default()],
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+/// for _ in 0..5 {
+///     agent.choose_action(&EnvironmentState::default()).unwrap();
+/// }
+///
+/// let mut resumed_agent: OrnsteinUhlenbeckAgent<f64> = OrnsteinUhlenbeckAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     vec![OrnsteinUhlenbeckParameters::default()],
+/// ).unwrap();
+/// resumed_agent.load(agent.store()).unwrap();
+///
+/// for _ in 0..20 {
+///     assert_eq!(
+///         agent.choose_action(&EnvironmentState::default()).unwrap(),
+///         resumed_agent.choose_action(&EnvironmentState::default()).unwrap(),
+///     );
+/// }
+/// ```
+pub struct OrnsteinUhlenbeckAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    parameters: Vec<OrnsteinUhlenbeckParameters>,
+    noise_state: Vec<f64>,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> OrnsteinUhlenbeckAgent<R> {
+    /// Creates a new OrnsteinUhlenbeckAgent with one set of parameters per dimension of the
+    /// ActionSpace in row-major order.
+    pub fn with(
+        action_spaces: ActionSpace,
+        parameters: Vec<OrnsteinUhlenbeckParameters>,
+    ) -> Result<Self, RandomAgentError> {
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        if boundaries.len() != parameters.len() {
+            return Err(RandomAgentError::ParameterCountMismatch {
+                expected: boundaries.len(),
+                actual: parameters.len(),
+            });
+        }
+        for (dimension, parameter) in parameters.iter().enumerate() {
+            if !(parameter.theta.is_finite() && parameter.mu.is_finite()) {
+                return Err(RandomAgentError::InvalidDistributionParameter {
+                    dimension,
+                    reason: "theta and mu have to be finite".to_string(),
+                });
+            }
+            if !(parameter.sigma.is_finite() && parameter.sigma >= 0.0) {
+                return Err(RandomAgentError::InvalidDistributionParameter {
+                    dimension,
+                    reason: format!(
+                        "sigma has to be finite and not negative, but is {}",
+                        parameter.sigma
+                    ),
+                });
+            }
+            if !(parameter.dt.is_finite() && parameter.dt > 0.0) {
+                return Err(RandomAgentError::InvalidDistributionParameter {
+                    dimension,
+                    reason: format!("dt has to be finite and positive, but is {}", parameter.dt),
+                });
+            }
+        }
+        let noise_state = parameters.iter().map(|parameter| parameter.mu).collect();
+        Ok(Self {
+            action_spaces,
+            boundaries,
+            parameters,
+            noise_state,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns the current noise per dimension in row-major order.
+    pub fn noise_state(&self) -> &[f64] {
+        &self.noise_state
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, OrnsteinUhlenbeckAgentStorage>
+    for OrnsteinUhlenbeckAgent<R>
+{
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        self.noise_state = self
+            .parameters
+            .iter()
+            .map(|parameter| parameter.mu)
+            .collect();
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let mut values = Vec::with_capacity(self.boundaries.len());
+        for ((noise, parameter), boundaries) in self
+            .noise_state
+            .iter_mut()
+            .zip(self.parameters.iter())
+            .zip(self.boundaries.iter())
+        {
+            let normal: f64 = StandardNormal.sample(&mut self.rng.rng);
+            *noise += parameter.theta * (parameter.mu - *noise) * parameter.dt
+                + parameter.sigma * parameter.dt.sqrt() * normal;
+            values.push(util::clamp_into(boundaries, *noise));
+        }
+        Ok(util::compose_action(
+            self.action_spaces.dimensions(),
+            values,
+        ))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: OrnsteinUhlenbeckAgentStorage) -> Result<(), RandomAgentError> {
+        if data.noise_state.len() != self.parameters.len() {
+            return Err(RandomAgentError::ParameterCountMismatch {
+                expected: self.parameters.len(),
+                actual: data.noise_state.len(),
+            });
+        }
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.noise_state = data.noise_state;
+        Ok(())
+    }
+
+    fn store(&self) -> OrnsteinUhlenbeckAgentStorage {
+        OrnsteinUhlenbeckAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            noise_state: self.noise_state.clone(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct OrnsteinUhlenbeckAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    noise_state: Vec<f64>,
+}
@@ -0,0 +1,219 @@
+//! Parametrised policies mapping EnvironmentStates into the ActionSpace.
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+use gymnarium_base::{ActionSpace, AgentAction, EnvironmentState};
+
+use rand::Rng;
+
+use rand_distr::StandardNormal;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util;
+use crate::RandomAgentError;
+
+/// Integer dimensions with at most this many values get one policy output per value and the
+/// value with the largest output is chosen. Larger ones get a single tanh-scaled and rounded
+/// output.
+pub const MAXIMUM_ARGMAX_CHOICES: i64 = 16;
+
+/// Weights and biases of a linear policy `W·state + b`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinearPolicyParameters {
+    /// One row per output, each containing one weight per input.
+    pub weights: Vec<Vec<f64>>,
+    /// One bias per output.
+    pub biases: Vec<f64>,
+}
+
+impl LinearPolicyParameters {
+    /// Creates parameters of the given shape which are all zero.
+    pub fn zeros(input_size: usize, output_size: usize) -> Self {
+        Self {
+            weights: vec![vec![0.0; input_size]; output_size],
+            biases: vec![0.0; output_size],
+        }
+    }
+
+    /// Draws all parameters from a normal distribution with mean zero.
+    pub(crate) fn sample<G: Rng>(
+        input_size: usize,
+        output_size: usize,
+        standard_deviation: f64,
+        rng: &mut G,
+    ) -> Self {
+        let mut draw = || standard_deviation * rng.sample::<f64, _>(StandardNormal);
+        Self {
+            weights: (0..output_size)
+                .map(|_| (0..input_size).map(|_| draw()).collect())
+                .collect(),
+            biases: (0..output_size).map(|_| draw()).collect(),
+        }
+    }
+
+    /// Returns the number of inputs.
+    pub fn input_size(&self) -> usize {
+        self.weights.first().map(Vec::len).unwrap_or(0)
+    }
+
+    /// Returns the number of outputs.
+    pub fn output_size(&self) -> usize {
+        self.biases.len()
+    }
+
+    /// Computes `W·inputs + b`.
+    pub fn evaluate(&self, inputs: &[f64]) -> Vec<f64> {
+        self.weights
+            .iter()
+            .zip(self.biases.iter())
+            .map(|(row, bias)| {
+                row.iter()
+                    .zip(inputs.iter())
+                    .map(|(weight, input)| weight * input)
+                    .sum::<f64>()
+                    + bias
+            })
+            .collect()
+    }
+
+    /// Returns `self + factor · other` for parameters of the same shape.
+    pub(crate) fn added(&self, other: &Self, factor: f64) -> Self {
+        Self {
+            weights: self
+                .weights
+                .iter()
+                .zip(other.weights.iter())
+                .map(|(row, other_row)| {
+                    row.iter()
+                        .zip(other_row.iter())
+                        .map(|(weight, other_weight)| weight + factor * other_weight)
+                        .collect()
+                })
+                .collect(),
+            biases: self
+                .biases
+                .iter()
+                .zip(other.biases.iter())
+                .map(|(bias, other_bias)| bias + factor * other_bias)
+                .collect(),
+        }
+    }
+
+    /// Fails if the parameters don't have the given shape.
+    pub(crate) fn check_shape(
+        &self,
+        input_size: usize,
+        output_size: usize,
+    ) -> Result<(), RandomAgentError> {
+        if self.biases.len() != output_size
+            || self.weights.len() != output_size
+            || self.weights.iter().any(|row| row.len() != input_size)
+        {
+            Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "linear policy parameters have to consist of {} rows of {} weights and {} biases",
+                    output_size, input_size, output_size
+                ),
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Reads the values of the state in row-major order as floats.
+pub(crate) fn state_features(
+    state: &EnvironmentState,
+    input_size: usize,
+) -> Result<Vec<f64>, RandomAgentError> {
+    let features: Vec<f64> = util::flatten_values(state.dimensions(), state)
+        .iter()
+        .map(util::value_as_f64)
+        .collect();
+    if features.len() != input_size {
+        return Err(RandomAgentError::StateSizeMismatch {
+            expected: input_size,
+            actual: features.len(),
+        });
+    }
+    Ok(features)
+}
+
+/// Maps the unbounded outputs of a policy into the ActionSpace.
+///
+/// Every float dimension gets one output which is squashed with `tanh` into finite boundaries
+/// (and clamped into infinite ones). Integer dimensions are handled as described at
+/// `MAXIMUM_ARGMAX_CHOICES`.
+pub(crate) struct OutputMapping {
+    dimensions: Vec<usize>,
+    boundaries: Vec<DimensionBoundaries>,
+    output_size: usize,
+}
+
+impl OutputMapping {
+    pub(crate) fn new(action_spaces: &ActionSpace) -> Self {
+        let boundaries = util::flatten_boundaries(action_spaces);
+        let output_size = boundaries.iter().map(Self::outputs_of).sum();
+        Self {
+            dimensions: action_spaces.dimensions().clone(),
+            boundaries,
+            output_size,
+        }
+    }
+
+    fn outputs_of(boundaries: &DimensionBoundaries) -> usize {
+        match boundaries {
+            DimensionBoundaries::Integer { minimum, maximum } => {
+                match maximum.checked_sub(*minimum) {
+                    Some(difference) if difference < MAXIMUM_ARGMAX_CHOICES => {
+                        difference as usize + 1
+                    }
+                    _ => 1,
+                }
+            }
+            DimensionBoundaries::Float { .. } => 1,
+        }
+    }
+
+    /// Returns the number of outputs the policy has to produce.
+    pub(crate) fn output_size(&self) -> usize {
+        self.output_size
+    }
+
+    /// Builds the action out of `output_size` outputs.
+    pub(crate) fn action(&self, outputs: &[f64]) -> AgentAction {
+        let mut values = Vec::with_capacity(self.boundaries.len());
+        let mut offset = 0;
+        for boundaries in &self.boundaries {
+            let count = Self::outputs_of(boundaries);
+            let outputs = &outputs[offset..offset + count];
+            offset += count;
+            values.push(match boundaries {
+                DimensionBoundaries::Integer { minimum, .. } if count > 1 => {
+                    let mut best = 0;
+                    for (index, output) in outputs.iter().enumerate() {
+                        if *output > outputs[best] {
+                            best = index;
+                        }
+                    }
+                    DimensionValue::Integer(minimum + best as i64)
+                }
+                DimensionBoundaries::Integer { minimum, maximum } => {
+                    let unit = (outputs[0].tanh() + 1.0) / 2.0;
+                    util::clamp_into(
+                        boundaries,
+                        *minimum as f64 * (1.0 - unit) + *maximum as f64 * unit,
+                    )
+                }
+                DimensionBoundaries::Float { minimum, maximum }
+                    if minimum.is_finite() && maximum.is_finite() =>
+                {
+                    let unit = (outputs[0].tanh() + 1.0) / 2.0;
+                    util::clamp_into(boundaries, minimum * (1.0 - unit) + maximum * unit)
+                }
+                DimensionBoundaries::Float { .. } => util::clamp_into(boundaries, outputs[0]),
+            });
+        }
+        util::compose_action(&self.dimensions, values)
+    }
+}
@@ -0,0 +1,196 @@
+//! Agent walking a low-discrepancy (Halton) sequence through the action space.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::DimensionBoundaries;
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::seq::SliceRandom;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::{self, SeededRng};
+use crate::RandomAgentError;
+
+/// Agent which chooses his actions by walking a Halton sequence over all dimensions of the
+/// ActionSpace.
+///
+/// Every dimension (in row-major order) uses the radical inverse in its own prime base, mapped
+/// into its boundaries. Compared to independent uniform draws the actions cover the space much
+/// more evenly. With scrambling enabled the digits of every dimension are permuted randomly
+/// depending on the seed, which breaks up the correlations between dimensions with large bases.
+///
+/// The sequence continues across episodes and restarts only on `Agent::reseed`.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::QuasiRandomAgent;
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut agent: QuasiRandomAgent<f64> = QuasiRandomAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(0.0..=1.0)]),
+///     false,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let first_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+/// let second_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_eq!(DimensionValue::Float(0.5), first_action[&[0]]);
+/// assert_eq!(DimensionValue::Float(0.25), second_action[&[0]]);
+/// ```
+pub struct QuasiRandomAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    boundaries: Vec<DimensionBoundaries>,
+    bases: Vec<u64>,
+    scrambled: bool,
+    digit_permutations: Vec<Vec<u64>>,
+    sequence_index: u64,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> QuasiRandomAgent<R> {
+    /// Creates a new QuasiRandomAgent with the provided ActionSpace, optionally scrambling the
+    /// sequence depending on the seed.
+    ///
+    /// Fails if any float boundaries are infinite.
+    pub fn with(action_spaces: ActionSpace, scrambled: bool) -> Result<Self, RandomAgentError> {
+        let boundaries = util::flatten_boundaries(&action_spaces);
+        util::require_finite_boundaries(&boundaries, "quasi-random sequence")?;
+        let bases = primes(boundaries.len());
+        let mut agent = Self {
+            action_spaces,
+            boundaries,
+            bases,
+            scrambled,
+            digit_permutations: Vec::new(),
+            sequence_index: 0,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        };
+        agent.generate_digit_permutations();
+        Ok(agent)
+    }
+
+    /// Returns the index of the next point of the sequence.
+    pub fn sequence_index(&self) -> u64 {
+        self.sequence_index
+    }
+
+    /// Creates one digit permutation per dimension, keeping zero in place so that trailing zero
+    /// digits don't change the value.
+    fn generate_digit_permutations(&mut self) {
+        let mut digit_permutations = Vec::with_capacity(self.bases.len());
+        for base in &self.bases {
+            let mut permutation: Vec<u64> = (0..*base).collect();
+            if self.scrambled {
+                permutation[1..].shuffle(&mut self.rng.rng);
+            }
+            digit_permutations.push(permutation);
+        }
+        self.digit_permutations = digit_permutations;
+    }
+}
+
+/// Returns the first `count` prime numbers.
+fn primes(count: usize) -> Vec<u64> {
+    let mut primes: Vec<u64> = Vec::with_capacity(count);
+    let mut candidate = 2;
+    while primes.len() < count {
+        if primes
+            .iter()
+            .take_while(|prime| *prime * *prime <= candidate)
+            .all(|prime| candidate % prime != 0)
+        {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+/// Returns the radical inverse of `index` in `base` with its digits permuted.
+fn radical_inverse(mut index: u64, base: u64, permutation: &[u64]) -> f64 {
+    let inverse_base = 1.0 / base as f64;
+    let mut factor = inverse_base;
+    let mut value = 0.0;
+    while index > 0 {
+        value += permutation[(index % base) as usize] as f64 * factor;
+        index /= base;
+        factor *= inverse_base;
+    }
+    value
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, QuasiRandomAgentStorage> for QuasiRandomAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        self.generate_digit_permutations();
+        self.sequence_index = 0;
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        // The point at index zero is skipped, as it lies on the minimum of every dimension.
+        self.sequence_index += 1;
+        let values = self
+            .boundaries
+            .iter()
+            .zip(self.bases.iter())
+            .zip(self.digit_permutations.iter())
+            .map(|((boundaries, base), permutation)| {
+                util::map_unit_into(
+                    boundaries,
+                    radical_inverse(self.sequence_index, *base, permutation),
+                )
+            })
+            .collect();
+        Ok(util::compose_action(
+            self.action_spaces.dimensions(),
+            values,
+        ))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: QuasiRandomAgentStorage) -> Result<(), RandomAgentError> {
+        self.rng.restore(data.last_seed, 0);
+        self.generate_digit_permutations();
+        self.sequence_index = data.sequence_index;
+        Ok(())
+    }
+
+    fn store(&self) -> QuasiRandomAgentStorage {
+        QuasiRandomAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            sequence_index: self.sequence_index,
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct QuasiRandomAgentStorage {
+    last_seed: Seed,
+    sequence_index: u64,
+}
@@ -0,0 +1,236 @@
+//! Agent searching randomly for the linear policy with the highest episode return.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use serde::{Deserialize, Serialize};
+
+use crate::policy::{self, LinearPolicyParameters, OutputMapping};
+use crate::util::SeededRng;
+use crate::RandomAgentError;
+
+/// Whether a RandomSearchAgent keeps searching or follows the best policy found so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SearchMode {
+    /// Draw new parameters every episode and remember the best ones.
+    Explore,
+    /// Follow the best parameters found so far without changing them.
+    ExploitBest,
+}
+
+/// Agent which draws new parameters for a linear policy (see `RandomLinearPolicyAgent`) every
+/// episode and keeps the ones which achieved the highest episode return.
+///
+/// The rewards passed to `process_reward` are summed up until it receives `done` or the agent is
+/// reset. In `SearchMode::ExploitBest` the best parameters are used for every episode instead (or
+/// new ones if nothing has been evaluated yet) and the best return isn't changed.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::{RandomSearchAgent, SearchMode};
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+///
+/// let mut agent: RandomSearchAgent<f64> = RandomSearchAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+///     1,
+///     1.0,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+///
+/// let state = EnvironmentState::simple(vec![DimensionValue::Float(1.0)]);
+/// for episode in 0..10 {
+///     agent.reset().unwrap();
+///     let action = agent.choose_action(&state).unwrap();
+///     agent.process_reward(&state, &action, &state, episode as f64, true).unwrap();
+/// }
+/// assert_eq!(Some(9.0), agent.best_return());
+///
+/// agent.set_mode(SearchMode::ExploitBest);
+/// agent.reset().unwrap();
+/// assert_eq!(agent.best_parameters(), Some(agent.parameters()));
+/// ```
+pub struct RandomSearchAgent<R: Reward + Into<f64>> {
+    mapping: OutputMapping,
+    input_size: usize,
+    standard_deviation: f64,
+    mode: SearchMode,
+    parameters: LinearPolicyParameters,
+    episode_return: f64,
+    episode_running: bool,
+    best: Option<(LinearPolicyParameters, f64)>,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward + Into<f64>> RandomSearchAgent<R> {
+    /// Creates a new RandomSearchAgent with the provided ActionSpace for states consisting of
+    /// `input_size` values, drawing its parameters with the given standard deviation.
+    pub fn with(
+        action_spaces: ActionSpace,
+        input_size: usize,
+        standard_deviation: f64,
+    ) -> Result<Self, RandomAgentError> {
+        if !standard_deviation.is_finite() || standard_deviation < 0.0 {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "standard deviation has to be finite and not negative, but is {}",
+                    standard_deviation
+                ),
+            });
+        }
+        let mapping = OutputMapping::new(&action_spaces);
+        let mut rng = SeededRng::new_random();
+        let parameters = LinearPolicyParameters::sample(
+            input_size,
+            mapping.output_size(),
+            standard_deviation,
+            &mut rng.rng,
+        );
+        Ok(Self {
+            mapping,
+            input_size,
+            standard_deviation,
+            mode: SearchMode::Explore,
+            parameters,
+            episode_return: 0.0,
+            episode_running: false,
+            best: None,
+            rng,
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns whether the agent explores or exploits.
+    pub fn mode(&self) -> SearchMode {
+        self.mode
+    }
+
+    /// Switches between exploring and exploiting, taking effect with the next reset.
+    pub fn set_mode(&mut self, mode: SearchMode) {
+        self.mode = mode;
+    }
+
+    /// Returns the parameters used within the current episode.
+    pub fn parameters(&self) -> &LinearPolicyParameters {
+        &self.parameters
+    }
+
+    /// Returns the return accumulated within the current episode.
+    pub fn episode_return(&self) -> f64 {
+        self.episode_return
+    }
+
+    /// Returns the parameters with the highest episode return found so far.
+    pub fn best_parameters(&self) -> Option<&LinearPolicyParameters> {
+        self.best.as_ref().map(|(parameters, _)| parameters)
+    }
+
+    /// Returns the highest episode return found so far.
+    pub fn best_return(&self) -> Option<f64> {
+        self.best
+            .as_ref()
+            .map(|(_, episode_return)| *episode_return)
+    }
+
+    fn finish_episode(&mut self) {
+        if !self.episode_running {
+            return;
+        }
+        self.episode_running = false;
+        if self.mode == SearchMode::Explore
+            && !matches!(self.best, Some((_, best_return)) if best_return >= self.episode_return)
+        {
+            self.best = Some((self.parameters.clone(), self.episode_return));
+        }
+    }
+}
+
+impl<R: Reward + Into<f64>> Agent<RandomAgentError, R, RandomSearchAgentStorage>
+    for RandomSearchAgent<R>
+{
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        self.finish_episode();
+        self.parameters = match (self.mode, &self.best) {
+            (SearchMode::ExploitBest, Some((parameters, _))) => parameters.clone(),
+            _ => LinearPolicyParameters::sample(
+                self.input_size,
+                self.mapping.output_size(),
+                self.standard_deviation,
+                &mut self.rng.rng,
+            ),
+        };
+        self.episode_return = 0.0;
+        Ok(())
+    }
+
+    fn choose_action(&mut self, state: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let features = policy::state_features(state, self.input_size)?;
+        Ok(self.mapping.action(&self.parameters.evaluate(&features)))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        reward: R,
+        done: bool,
+    ) -> Result<(), RandomAgentError> {
+        self.episode_return += reward.into();
+        self.episode_running = true;
+        if done {
+            self.finish_episode();
+        }
+        Ok(())
+    }
+
+    fn load(&mut self, data: RandomSearchAgentStorage) -> Result<(), RandomAgentError> {
+        let output_size = self.mapping.output_size();
+        data.parameters.check_shape(self.input_size, output_size)?;
+        if let Some((parameters, _)) = &data.best {
+            parameters.check_shape(self.input_size, output_size)?;
+        }
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.mode = data.mode;
+        self.parameters = data.parameters;
+        self.episode_return = data.episode_return;
+        self.episode_running = data.episode_running;
+        self.best = data.best;
+        Ok(())
+    }
+
+    fn store(&self) -> RandomSearchAgentStorage {
+        RandomSearchAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            mode: self.mode,
+            parameters: self.parameters.clone(),
+            episode_return: self.episode_return,
+            episode_running: self.episode_running,
+            best: self.best.clone(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RandomSearchAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    mode: SearchMode,
+    parameters: LinearPolicyParameters,
+    episode_return: f64,
+    episode_running: bool,
+    best: Option<(LinearPolicyParameters, f64)>,
+}
@@ -0,0 +1,155 @@
+//! Agent whose random action depends only on the seed and the environment state.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::space::DimensionValue;
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::SeedableRng;
+
+use rand_chacha::ChaCha20Rng;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util;
+use crate::RandomAgentError;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Agent which always chooses the same random action for the same EnvironmentState, a "frozen"
+/// random policy.
+///
+/// For every state a fresh `ChaCha20Rng` is created from the seed, using the hash of the state
+/// (see `StateHashedRandomAgent::hash_state`) as its stream, and the action is sampled from it like
+/// `RandomAgent` does. The chosen actions therefore don't depend on the order in which states are
+/// visited.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::StateHashedRandomAgent;
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: StateHashedRandomAgent<f64> = StateHashedRandomAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(-1.0..=1.0)]),
+/// );
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let first_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+/// let second_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_eq!(first_action, second_action);
+/// ```
+pub struct StateHashedRandomAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    last_seed: Seed,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> StateHashedRandomAgent<R> {
+    /// Creates a new StateHashedRandomAgent with the provided ActionSpace.
+    pub fn with(action_spaces: ActionSpace) -> Self {
+        Self {
+            action_spaces,
+            last_seed: Seed::new_random(),
+            _phantom_data: PhantomData::default(),
+        }
+    }
+
+    /// Returns the stable hash of the state.
+    ///
+    /// The hash is the 64 bit FNV-1a hash of the following little-endian byte encoding: the number
+    /// of dimensions and every dimension as `u64`, followed by every value in row-major order as
+    /// a tag byte (`0` for integers, `1` for floats) and its eight bytes (`i64` for integers, the
+    /// IEEE 754 bits for floats with `-0.0` written as `0.0` and every NaN as the canonical NaN).
+    ///
+    /// It neither depends on the platform nor on the process and only changes with a new major
+    /// version of this crate.
+    pub fn hash_state(state: &EnvironmentState) -> u64 {
+        let mut hash = FNV_OFFSET_BASIS;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes {
+                hash ^= u64::from(*byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        };
+        let dimensions = state.dimensions();
+        write(&(dimensions.len() as u64).to_le_bytes());
+        for dimension in dimensions.iter() {
+            write(&(*dimension as u64).to_le_bytes());
+        }
+        for value in util::flatten_values(dimensions, state) {
+            match value {
+                DimensionValue::Integer(value) => {
+                    write(&[0]);
+                    write(&value.to_le_bytes());
+                }
+                DimensionValue::Float(value) => {
+                    let value = if value.is_nan() {
+                        f64::NAN
+                    } else if value == 0.0 {
+                        0.0
+                    } else {
+                        value
+                    };
+                    write(&[1]);
+                    write(&value.to_bits().to_le_bytes());
+                }
+            }
+        }
+        hash
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, StateHashedRandomAgentStorage>
+    for StateHashedRandomAgent<R>
+{
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.last_seed = random_seed.unwrap_or_else(Seed::new_random);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn choose_action(&mut self, state: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let mut rng = ChaCha20Rng::from_seed(self.last_seed.clone().into());
+        rng.set_stream(Self::hash_state(state));
+        Ok(self.action_spaces.sample_with(&mut rng))
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: StateHashedRandomAgentStorage) -> Result<(), RandomAgentError> {
+        self.last_seed = data.last_seed;
+        Ok(())
+    }
+
+    fn store(&self) -> StateHashedRandomAgentStorage {
+        StateHashedRandomAgentStorage {
+            last_seed: self.last_seed.clone(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StateHashedRandomAgentStorage {
+    last_seed: Seed,
+}
@@ -0,0 +1,164 @@
+//! Agent repeating its previous action with a fixed probability.
+
+use std::marker::PhantomData;
+
+use gymnarium_base::{ActionSpace, Agent, AgentAction, EnvironmentState, Reward, Seed};
+
+use rand::Rng;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::SeededRng;
+use crate::RandomAgentError;
+
+/// Agent which chooses his actions like `RandomAgent`, but sticks to his previous action with the
+/// probability `repeat_probability`.
+///
+/// The first action after `Agent::reset` is always sampled anew.
+///
+/// # Example
+///
+/// ```
+/// use gymnarium_agents_random::StickyRandomAgent;
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: StickyRandomAgent<f64> = StickyRandomAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(0..=17)]),
+///     1.0,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+///
+/// let first_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+/// let second_action = agent.choose_action(&EnvironmentState::default()).unwrap();
+///
+/// assert_eq!(first_action, second_action);
+/// ```
+///
+/// Storing and loading resumes the exact trajectory:
+///
+/// ```
+/// use gymnarium_agents_random::StickyRandomAgent;
+/// use gymnarium_base::{ActionSpace, Seed, Agent, EnvironmentState};
+/// use gymnarium_base::space::DimensionBoundaries;
+///
+/// let mut agent: StickyRandomAgent<f64> = StickyRandomAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(0..=17)]),
+///     0.5,
+/// ).unwrap();
+/// agent.reseed(Some(Seed::from(0))).unwrap();
+/// agent.reset().unwrap();
+/// for _ in 0..5 {
+///     agent.choose_action(&EnvironmentState::default()).unwrap();
+/// }
+///
+/// let mut resumed_agent: StickyRandomAgent<f64> = StickyRandomAgent::with(
+///     ActionSpace::simple(vec![DimensionBoundaries::from(0..=17)]),
+///     0.5,
+/// ).unwrap();
+/// resumed_agent.load(agent.store()).unwrap();
+///
+/// for _ in 0..20 {
+///     assert_eq!(
+///         agent.choose_action(&EnvironmentState::default()).unwrap(),
+///         resumed_agent.choose_action(&EnvironmentState::default()).unwrap(),
+///     );
+/// }
+/// ```
+pub struct StickyRandomAgent<R: Reward> {
+    action_spaces: ActionSpace,
+    repeat_probability: f64,
+    previous_action: Option<AgentAction>,
+    rng: SeededRng,
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: Reward> StickyRandomAgent<R> {
+    /// Creates a new StickyRandomAgent with the provided ActionSpace, which repeats his previous
+    /// action with `repeat_probability` (between 0 and 1).
+    pub fn with(
+        action_spaces: ActionSpace,
+        repeat_probability: f64,
+    ) -> Result<Self, RandomAgentError> {
+        if !(0.0..=1.0).contains(&repeat_probability) {
+            return Err(RandomAgentError::InvalidConfiguration {
+                reason: format!(
+                    "repeat probability has to be between 0 and 1, but is {}",
+                    repeat_probability
+                ),
+            });
+        }
+        Ok(Self {
+            action_spaces,
+            repeat_probability,
+            previous_action: None,
+            rng: SeededRng::new_random(),
+            _phantom_data: PhantomData::default(),
+        })
+    }
+
+    /// Returns the probability with which the previous action gets repeated.
+    pub fn repeat_probability(&self) -> f64 {
+        self.repeat_probability
+    }
+}
+
+impl<R: Reward> Agent<RandomAgentError, R, StickyRandomAgentStorage> for StickyRandomAgent<R> {
+    fn reseed(&mut self, random_seed: Option<Seed>) -> Result<(), RandomAgentError> {
+        self.rng.reseed(random_seed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<(), RandomAgentError> {
+        self.previous_action = None;
+        Ok(())
+    }
+
+    fn choose_action(&mut self, _: &EnvironmentState) -> Result<AgentAction, RandomAgentError> {
+        let action = match &self.previous_action {
+            Some(previous_action) if self.rng.rng.gen_bool(self.repeat_probability) => {
+                previous_action.clone()
+            }
+            _ => self.action_spaces.sample_with(&mut self.rng.rng),
+        };
+        self.previous_action = Some(action.clone());
+        Ok(action)
+    }
+
+    fn process_reward(
+        &mut self,
+        _: &EnvironmentState,
+        _: &AgentAction,
+        _: &EnvironmentState,
+        _: R,
+        _: bool,
+    ) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+
+    fn load(&mut self, data: StickyRandomAgentStorage) -> Result<(), RandomAgentError> {
+        self.rng.restore(data.last_seed, data.rng_word_pos);
+        self.previous_action = data.previous_action;
+        Ok(())
+    }
+
+    fn store(&self) -> StickyRandomAgentStorage {
+        StickyRandomAgentStorage {
+            last_seed: self.rng.last_seed.clone(),
+            rng_word_pos: self.rng.word_pos(),
+            previous_action: self.previous_action.clone(),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), RandomAgentError> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StickyRandomAgentStorage {
+    last_seed: Seed,
+    rng_word_pos: u128,
+    previous_action: Option<AgentAction>,
+}
@@ -0,0 +1,204 @@
+//! Helpers shared between the agents of this crate.
+//!
+//! Every agent works on the *flattened* view of an `ActionSpace`, meaning all positions of the
+//! space enumerated in row-major order. The functions here convert between that flat view and
+//! the shaped `gymnarium_base` types.
+
+use std::ops::Index;
+
+use gymnarium_base::space::{DimensionBoundaries, DimensionValue};
+use gymnarium_base::{ActionSpace, AgentAction, Seed};
+
+use rand::distributions::{Distribution, Uniform};
+use rand::{Rng, SeedableRng};
+
+use rand_chacha::ChaCha20Rng;
+
+use rand_distr::StandardNormal;
+
+use crate::RandomAgentError;
+
+/// Enumerates every position within the given dimensions in row-major order.
+pub(crate) fn positions(dimensions: &[usize]) -> Vec<Vec<usize>> {
+    let count: usize = dimensions.iter().product();
+    let mut positions = Vec::with_capacity(count);
+    if count == 0 {
+        return positions;
+    }
+    let mut position = vec![0usize; dimensions.len()];
+    for _ in 0..count {
+        positions.push(position.clone());
+        for (index, dimension) in dimensions.iter().enumerate().rev() {
+            position[index] += 1;
+            if position[index] < *dimension {
+                break;
+            }
+            position[index] = 0;
+        }
+    }
+    positions
+}
+
+/// Returns the boundaries of the action space in row-major order.
+pub(crate) fn flatten_boundaries(action_space: &ActionSpace) -> Vec<DimensionBoundaries> {
+    positions(action_space.dimensions())
+        .iter()
+        .map(|position| action_space[&position[..]].clone())
+        .collect()
+}
+
+/// Returns the values of an action or state in row-major order.
+pub(crate) fn flatten_values<V>(dimensions: &[usize], values: &V) -> Vec<DimensionValue>
+where
+    V: for<'a> Index<&'a [usize], Output = DimensionValue>,
+{
+    positions(dimensions)
+        .iter()
+        .map(|position| values[&position[..]].clone())
+        .collect()
+}
+
+/// Builds an action with the given dimensions out of row-major ordered values.
+pub(crate) fn compose_action(dimensions: &[usize], values: Vec<DimensionValue>) -> AgentAction {
+    AgentAction::new(dimensions.to_vec(), values)
+}
+
+/// Returns the value as float, converting integers.
+pub(crate) fn value_as_f64(value: &DimensionValue) -> f64 {
+    match value {
+        DimensionValue::Integer(value) => *value as f64,
+        DimensionValue::Float(value) => *value,
+    }
+}
+
+/// Draws a value uniformly from within the boundaries.
+pub(crate) fn sample_uniform<G: Rng>(
+    boundaries: &DimensionBoundaries,
+    rng: &mut G,
+) -> DimensionValue {
+    match boundaries {
+        DimensionBoundaries::Integer { minimum, maximum } => {
+            DimensionValue::Integer(Uniform::new_inclusive(*minimum, *maximum).sample(rng))
+        }
+        DimensionBoundaries::Float { minimum, maximum } => {
+            DimensionValue::Float(Uniform::new_inclusive(*minimum, *maximum).sample(rng))
+        }
+    }
+}
+
+/// Draws a value within the boundaries like `sample_uniform`, but without panicking if they are
+/// too wide for a uniform draw, e.g. infinite ones.
+///
+/// Finite float boundaries whose width overflows are still sampled uniformly. Infinite ones get
+/// values normally distributed around their finite end (or zero) instead.
+pub(crate) fn sample_valid<G: Rng>(
+    boundaries: &DimensionBoundaries,
+    rng: &mut G,
+) -> DimensionValue {
+    match boundaries {
+        DimensionBoundaries::Float { minimum, maximum } if !(*maximum - *minimum).is_finite() => {
+            if minimum.is_finite() && maximum.is_finite() {
+                return map_unit_into(boundaries, rng.gen());
+            }
+            let center = if minimum.is_finite() {
+                *minimum
+            } else if maximum.is_finite() {
+                *maximum
+            } else {
+                0.0
+            };
+            clamp_into(boundaries, center + rng.sample::<f64, _>(StandardNormal))
+        }
+        _ => sample_uniform(boundaries, rng),
+    }
+}
+
+/// Maps `unit` from `[0, 1]` into the boundaries.
+///
+/// Integer boundaries are split into equally sized buckets, one per contained integer. Float
+/// boundaries have to be finite (see `require_finite_boundaries`).
+pub(crate) fn map_unit_into(boundaries: &DimensionBoundaries, unit: f64) -> DimensionValue {
+    let unit = unit.max(0.0).min(1.0);
+    match boundaries {
+        DimensionBoundaries::Integer { minimum, maximum } => {
+            let difference = *maximum as i128 - *minimum as i128;
+            let offset = ((unit * (difference as f64 + 1.0)).floor() as i128).min(difference);
+            DimensionValue::Integer((*minimum as i128 + offset) as i64)
+        }
+        DimensionBoundaries::Float { minimum, maximum } => DimensionValue::Float(
+            (minimum * (1.0 - unit) + maximum * unit)
+                .max(*minimum)
+                .min(*maximum),
+        ),
+    }
+}
+
+/// Fails if any float boundaries are infinite, naming the agent requiring finite ones.
+pub(crate) fn require_finite_boundaries(
+    boundaries: &[DimensionBoundaries],
+    agent: &str,
+) -> Result<(), RandomAgentError> {
+    for (dimension, boundaries) in boundaries.iter().enumerate() {
+        if let DimensionBoundaries::Float { minimum, maximum } = boundaries {
+            if !(minimum.is_finite() && maximum.is_finite()) {
+                return Err(RandomAgentError::IncompatibleDistribution {
+                    dimension,
+                    reason: format!("{} requires finite boundaries", agent),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Clamps a float into the float boundaries or rounds and clamps it into integer boundaries.
+pub(crate) fn clamp_into(boundaries: &DimensionBoundaries, value: f64) -> DimensionValue {
+    match boundaries {
+        DimensionBoundaries::Integer { minimum, maximum } => {
+            DimensionValue::Integer((value.round() as i64).max(*minimum).min(*maximum))
+        }
+        DimensionBoundaries::Float { minimum, maximum } => {
+            DimensionValue::Float(value.max(*minimum).min(*maximum))
+        }
+    }
+}
+
+/// A `ChaCha20Rng` together with the seed it was created from.
+///
+/// This bundles the seeding approach of `RandomAgent`, so that the state can be restored through
+/// the seed and the word position of the generator.
+pub(crate) struct SeededRng {
+    pub(crate) last_seed: Seed,
+    pub(crate) rng: ChaCha20Rng,
+}
+
+impl SeededRng {
+    pub(crate) fn new_random() -> Self {
+        let last_seed = Seed::new_random();
+        Self {
+            last_seed: last_seed.clone(),
+            rng: ChaCha20Rng::from_seed(last_seed.into()),
+        }
+    }
+
+    pub(crate) fn reseed(&mut self, random_seed: Option<Seed>) {
+        self.last_seed = random_seed.unwrap_or_else(Seed::new_random);
+        self.rng = ChaCha20Rng::from_seed(self.last_seed.clone().into());
+    }
+
+    pub(crate) fn restore(&mut self, last_seed: Seed, rng_word_pos: u128) {
+        self.last_seed = last_seed;
+        self.rng = ChaCha20Rng::from_seed(self.last_seed.clone().into());
+        self.rng.set_word_pos(rng_word_pos);
+    }
+
+    pub(crate) fn word_pos(&self) -> u128 {
+        self.rng.get_word_pos()
+    }
+
+    /// Draws the seed for a wrapped agent, so that its generator doesn't produce the same words
+    /// as this one.
+    pub(crate) fn derive_seed(&mut self) -> Seed {
+        Seed::from(self.rng.gen::<u64>())
+    }
+}